@@ -49,6 +49,46 @@ impl Code {
     pub fn as_i32(&self) -> i32 {
         *self as i32
     }
+
+    // 默认英文提示，作为多语言资源缺失时的兜底文案
+    pub fn en(&self) -> &'static str {
+        match self {
+            Code::Ok => "Ok",
+            Code::BadRequest => "Bad request",
+            Code::Unauthorized => "Unauthorized",
+            Code::Forbidden => "Forbidden",
+            Code::NotFound => "Not found",
+            Code::MethodNotAllowed => "Method not allowed",
+            Code::TooManyRequests => "Too many requests",
+            Code::IdentifyError => "Identity verification failed",
+            Code::IdentifyExpired => "Identity has expired",
+            Code::SignError => "Invalid signature",
+            Code::InternalServerError => "Internal server error",
+            Code::FileTooLarge => "File too large",
+            Code::MissingHeader => "Missing required header",
+            Code::IllegalParam => "Illegal parameter",
+        }
+    }
+
+    // 多语言资源中使用的符号键，避免语言文件直接依赖数字编码
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            Code::Ok => "ok",
+            Code::BadRequest => "bad_request",
+            Code::Unauthorized => "unauthorized",
+            Code::Forbidden => "forbidden",
+            Code::NotFound => "not_found",
+            Code::MethodNotAllowed => "method_not_allowed",
+            Code::TooManyRequests => "too_many_requests",
+            Code::IdentifyError => "identify_error",
+            Code::IdentifyExpired => "identify_expired",
+            Code::SignError => "sign_error",
+            Code::InternalServerError => "internal_server_error",
+            Code::FileTooLarge => "file_too_large",
+            Code::MissingHeader => "missing_header",
+            Code::IllegalParam => "illegal_param",
+        }
+    }
 }
 
 impl std::fmt::Display for Code {
@@ -64,3 +104,19 @@ fn test_code() {
     assert_eq!(Code::Ok.to_string(), "200");
     assert_eq!(format!("{}", Code::InternalServerError), "500");
 }
+
+#[test]
+fn test_code_en() {
+    assert_eq!(Code::Ok.en(), "Ok");
+    assert_eq!(Code::NotFound.en(), "Not found");
+    assert_eq!(Code::InternalServerError.en(), "Internal server error");
+    assert_eq!(Code::IllegalParam.en(), "Illegal parameter");
+}
+
+#[test]
+fn test_code_i18n_key() {
+    assert_eq!(Code::Ok.i18n_key(), "ok");
+    assert_eq!(Code::NotFound.i18n_key(), "not_found");
+    assert_eq!(Code::InternalServerError.i18n_key(), "internal_server_error");
+    assert_eq!(Code::IllegalParam.i18n_key(), "illegal_param");
+}
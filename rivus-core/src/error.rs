@@ -0,0 +1,101 @@
+use std::fmt;
+use crate::code::Code;
+
+#[derive(Debug)]
+pub struct Error {
+    pub code: i32,
+    pub message: String,
+    pub args: Vec<(String, String)>,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Error {{ code: {}, message: {}", self.code, self.message)?;
+        if !self.args.is_empty() {
+            write!(f, ", args: {:?}", self.args)?;
+        }
+        write!(f, " }}")
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl Error {
+    pub fn new(code: i32) -> Self {
+        Self {
+            code,
+            message: String::new(),
+            args: vec![],
+        }
+    }
+
+    pub fn with_message(mut self, msg: impl Into<String>) -> Self {
+        self.message = msg.into();
+        self
+    }
+
+    pub fn with_arg(mut self, key: impl Into<String>, val: impl Into<String>) -> Self {
+        self.args.push((key.into(), val.into()));
+        self
+    }
+
+    /// 按名称查找参数值，存在同名参数时返回最先添加的一个
+    pub fn arg(&self, key: &str) -> Option<&str> {
+        self.args
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::new(Code::InternalServerError.as_i32()).with_message(err.to_string())
+    }
+}
+
+impl From<serde_yaml::Error> for Error {
+    fn from(err: serde_yaml::Error) -> Self {
+        Error::new(Code::InternalServerError.as_i32()).with_message(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::new(Code::InternalServerError.as_i32()).with_message(err.to_string())
+    }
+}
+
+impl From<toml::de::Error> for Error {
+    fn from(err: toml::de::Error) -> Self {
+        Error::new(Code::InternalServerError.as_i32()).with_message(err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arg_lookup() {
+        let err = Error::new(Code::IllegalParam.as_i32())
+            .with_arg("field", "name")
+            .with_arg("min", "1");
+        assert_eq!(err.arg("field"), Some("name"));
+        assert_eq!(err.arg("min"), Some("1"));
+        assert_eq!(err.arg("max"), None);
+    }
+
+    #[test]
+    fn test_into_anyhow() {
+        fn fail() -> anyhow::Result<()> {
+            Err(Error::new(Code::NotFound.as_i32()).with_message("user not found"))?;
+            Ok(())
+        }
+
+        let err = fail().unwrap_err();
+        assert!(err.to_string().contains("user not found"));
+        let core = err.downcast_ref::<Error>().unwrap();
+        assert_eq!(core.code, 404);
+    }
+}
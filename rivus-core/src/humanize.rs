@@ -0,0 +1,182 @@
+//! 人类可读单位的 serde 辅助模块
+//!
+//! 用于配置结构体字段，例如：
+//!
+//! ```
+//! use serde::Deserialize;
+//! use std::time::Duration;
+//!
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     #[serde(with = "rivus_core::humanize::duration")]
+//!     cleanup_interval: Duration,
+//!     #[serde(with = "rivus_core::humanize::size")]
+//!     body_limit: u64,
+//! }
+//! ```
+
+/// 时长，如 `30s`、`1h`、`1h 30m`，格式由 humantime 解析
+pub mod duration {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&humantime::format_duration(*duration))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        humantime::parse_duration(&s)
+            .map_err(|e| serde::de::Error::custom(format!("invalid duration '{}': {}", s, e)))
+    }
+}
+
+/// 字节大小，如 `512`、`10MB`、`512KiB`
+///
+/// `KB`/`MB`/`GB`/`TB` 按 1000 进制，`KiB`/`MiB`/`GiB`/`TiB` 按 1024 进制，单位不区分大小写。
+/// 也接受直接写整数字节数。
+pub mod size {
+    use serde::de::{self, Visitor};
+    use serde::{Deserializer, Serializer};
+    use std::fmt;
+
+    pub fn serialize<S>(size: &u64, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u64(*size)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(SizeVisitor)
+    }
+
+    /// 解析带单位的字节大小
+    pub fn parse(s: &str) -> Result<u64, String> {
+        let s = s.trim();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (number, unit) = s.split_at(split);
+        if number.is_empty() {
+            return Err(format!("invalid size '{}': missing number", s));
+        }
+        let number: u64 = number
+            .parse()
+            .map_err(|e| format!("invalid size '{}': {}", s, e))?;
+        let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+            "" | "b" => 1,
+            "kb" => 1_000,
+            "mb" => 1_000_000,
+            "gb" => 1_000_000_000,
+            "tb" => 1_000_000_000_000,
+            "kib" => 1 << 10,
+            "mib" => 1 << 20,
+            "gib" => 1 << 30,
+            "tib" => 1 << 40,
+            other => return Err(format!("invalid size '{}': unknown unit '{}'", s, other)),
+        };
+        number
+            .checked_mul(multiplier)
+            .ok_or_else(|| format!("invalid size '{}': overflow", s))
+    }
+
+    struct SizeVisitor;
+
+    impl Visitor<'_> for SizeVisitor {
+        type Value = u64;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a byte count or a size string like \"10MB\"")
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<u64, E> {
+            Ok(v)
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<u64, E> {
+            u64::try_from(v).map_err(|_| E::custom(format!("invalid size: {}", v)))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<u64, E> {
+            parse(v).map_err(E::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use std::time::Duration;
+
+    #[derive(Deserialize, Serialize, Debug, PartialEq)]
+    struct Config {
+        #[serde(with = "super::duration")]
+        interval: Duration,
+        #[serde(with = "super::size")]
+        limit: u64,
+    }
+
+    fn parse(yaml: &str) -> Result<Config, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+
+    #[test]
+    fn test_duration_units() {
+        assert_eq!(
+            parse("interval: 1h\nlimit: 1").unwrap().interval,
+            Duration::from_secs(3600)
+        );
+        assert_eq!(
+            parse("interval: 30s\nlimit: 1").unwrap().interval,
+            Duration::from_secs(30)
+        );
+        assert_eq!(
+            parse("interval: 1h 30m\nlimit: 1").unwrap().interval,
+            Duration::from_secs(5400)
+        );
+        assert_eq!(
+            parse("interval: 250ms\nlimit: 1").unwrap().interval,
+            Duration::from_millis(250)
+        );
+        assert!(parse("interval: soon\nlimit: 1").is_err());
+        assert!(parse("interval: 10\nlimit: 1").is_err());
+    }
+
+    #[test]
+    fn test_size_units() {
+        assert_eq!(
+            parse("interval: 1s\nlimit: 10MB").unwrap().limit,
+            10_000_000
+        );
+        assert_eq!(
+            parse("interval: 1s\nlimit: 512KiB").unwrap().limit,
+            512 * 1024
+        );
+        assert_eq!(parse("interval: 1s\nlimit: 2 gib").unwrap().limit, 2 << 30);
+        assert_eq!(parse("interval: 1s\nlimit: 64B").unwrap().limit, 64);
+        assert_eq!(parse("interval: 1s\nlimit: 4096").unwrap().limit, 4096);
+        assert!(parse("interval: 1s\nlimit: 10XB").is_err());
+        assert!(parse("interval: 1s\nlimit: MB").is_err());
+        assert!(parse("interval: 1s\nlimit: -1").is_err());
+        assert!(parse("interval: 1s\nlimit: 99999999999TB").is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let config = Config {
+            interval: Duration::from_secs(90),
+            limit: 1024,
+        };
+        let yaml = serde_yaml::to_string(&config).unwrap();
+        assert!(yaml.contains("1m 30s"));
+        assert_eq!(parse(&yaml).unwrap(), config);
+    }
+}
@@ -1,3 +1,4 @@
 pub mod utils;
 pub mod error;
 pub mod code;
+pub mod humanize;
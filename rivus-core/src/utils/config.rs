@@ -0,0 +1,299 @@
+//! 按扩展名自动识别格式的配置加载入口
+
+use serde::Deserializer;
+use serde::de::DeserializeOwned;
+use std::fmt::Display;
+use std::path::Path;
+use crate::code::Code;
+use crate::error::Error;
+use crate::utils::yaml;
+
+/// 支持的配置文件格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Yaml,
+    Toml,
+    Json,
+}
+
+/// 根据文件名识别格式，`.gz` 后缀按其内层扩展名识别（如 `app.toml.gz`）
+fn detect_format(path: &Path) -> Option<Format> {
+    let name = path.file_name()?.to_str()?.to_lowercase();
+    let name = name.strip_suffix(".gz").unwrap_or(&name);
+    match Path::new(name).extension()?.to_str()? {
+        "yaml" | "yml" => Some(Format::Yaml),
+        "toml" => Some(Format::Toml),
+        "json" => Some(Format::Json),
+        _ => None,
+    }
+}
+
+/// 加载配置文件，按扩展名选择解析器
+///
+/// 支持 `.yaml`/`.yml`、`.toml`、`.json` 及其 gzip 压缩形式（`.gz`），
+/// 各格式统一进行 `${VAR}` 环境变量替换。YAML 额外支持 `!include`（见 [`yaml::load_from_file`]）。
+/// 无法识别的扩展名返回错误。
+///
+/// TOML/JSON 先解析再替换，占位符只在字符串值内生效，因此变量值中的引号、
+/// 反斜杠等字符不会破坏或改变文档结构。字符串恰好为单个占位符（如 `"${PORT:8080}"`）时，
+/// 替换结果与 YAML 一致地按标量解析，可得到数字和布尔值；部分替换的结果保持字符串。
+pub fn load_config<T: DeserializeOwned, P: AsRef<Path>>(path: P) -> Result<T, Error> {
+    let path = path.as_ref();
+    let format = detect_format(path).ok_or_else(|| {
+        Error::new(Code::InternalServerError.as_i32())
+            .with_message(format!("Unsupported config file format: {}", path.display()))
+    })?;
+
+    match format {
+        Format::Yaml => yaml::load_from_file(path),
+        Format::Toml => {
+            let content = yaml::read_config_file(path)?;
+            let mut value: toml::Value = toml::from_str(&content)?;
+            if !replace_toml_vars(&mut value)? {
+                // 无占位符时直接从文本反序列化，保留错误的行列位置
+                return Ok(toml::from_str(&content)?);
+            }
+            deserialize_value(value, path)
+        }
+        Format::Json => {
+            let content = yaml::read_config_file(path)?;
+            let mut value: serde_json::Value = serde_json::from_str(&content)?;
+            if !replace_json_vars(&mut value)? {
+                return Ok(serde_json::from_str(&content)?);
+            }
+            deserialize_value(value, path)
+        }
+    }
+}
+
+/// 替换字符串中的占位符，未发生替换时返回 None
+///
+/// 返回替换后的字符串，以及单个占位符时按标量解析的结果。
+fn substitute(s: &str) -> Result<Option<(String, Option<serde_yaml::Value>)>, Error> {
+    let replaced = yaml::replace_vars(s)?;
+    if replaced == s {
+        return Ok(None);
+    }
+    let scalar = yaml::is_single_placeholder(s)
+        .then(|| yaml::parse_env_value(&serde_yaml::Value::Null, &replaced));
+    Ok(Some((replaced, scalar)))
+}
+
+/// 替换 TOML 字符串值中的占位符，返回是否发生了替换
+fn replace_toml_vars(value: &mut toml::Value) -> Result<bool, Error> {
+    let mut changed = false;
+    match value {
+        toml::Value::String(s) => {
+            if let Some((replaced, scalar)) = substitute(s)? {
+                // TOML 没有 null，无法表示的标量保持字符串
+                *value = scalar
+                    .and_then(|scalar| toml::Value::try_from(scalar).ok())
+                    .unwrap_or(toml::Value::String(replaced));
+                changed = true;
+            }
+        }
+        toml::Value::Array(items) => {
+            for item in items {
+                changed |= replace_toml_vars(item)?;
+            }
+        }
+        toml::Value::Table(table) => {
+            for (_, item) in table.iter_mut() {
+                changed |= replace_toml_vars(item)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(changed)
+}
+
+/// 替换 JSON 字符串值中的占位符，返回是否发生了替换
+fn replace_json_vars(value: &mut serde_json::Value) -> Result<bool, Error> {
+    let mut changed = false;
+    match value {
+        serde_json::Value::String(s) => {
+            if let Some((replaced, scalar)) = substitute(s)? {
+                *value = scalar
+                    .and_then(|scalar| serde_json::to_value(scalar).ok())
+                    .unwrap_or(serde_json::Value::String(replaced));
+                changed = true;
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                changed |= replace_json_vars(item)?;
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for (_, item) in map.iter_mut() {
+                changed |= replace_json_vars(item)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(changed)
+}
+
+/// 从已解析的值反序列化，错误中附带文件路径与出错字段路径
+fn deserialize_value<'de, D, T>(value: D, path: &Path) -> Result<T, Error>
+where
+    D: Deserializer<'de>,
+    D::Error: Display,
+    T: DeserializeOwned,
+{
+    serde_path_to_error::deserialize(value).map_err(|e| {
+        Error::new(Code::InternalServerError.as_i32()).with_message(format!(
+            "{}: {} at {}",
+            path.display(),
+            e.inner(),
+            e.path()
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+    use serde::Deserialize;
+    use std::env;
+    use std::fs;
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Server {
+        host: String,
+        port: u16,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Config {
+        name: String,
+        debug: bool,
+        server: Server,
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("rivus_config_{}", name));
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_load_same_config_from_each_format() {
+        unsafe {
+            env::set_var("TEST_CONFIG_HOST", "example.com");
+            env::set_var("TEST_CONFIG_PORT", "8081");
+        }
+        let dir = temp_dir("formats");
+        fs::write(
+            dir.join("app.yaml"),
+            "name: app\ndebug: ${TEST_CONFIG_DEBUG:true}\nserver:\n  host: ${TEST_CONFIG_HOST}\n  port: ${TEST_CONFIG_PORT:8080}\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("app.toml"),
+            "name = \"app\"\ndebug = \"${TEST_CONFIG_DEBUG:true}\"\n\n[server]\nhost = \"${TEST_CONFIG_HOST}\"\nport = \"${TEST_CONFIG_PORT:8080}\"\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("app.json"),
+            r#"{"name":"app","debug":"${TEST_CONFIG_DEBUG:true}","server":{"host":"${TEST_CONFIG_HOST}","port":"${TEST_CONFIG_PORT:8080}"}}"#,
+        )
+        .unwrap();
+
+        let expected = Config {
+            name: "app".to_string(),
+            debug: true,
+            server: Server {
+                host: "example.com".to_string(),
+                port: 8081,
+            },
+        };
+        for file in ["app.yaml", "app.toml", "app.json"] {
+            let config: Config = load_config(dir.join(file)).unwrap();
+            assert_eq!(config, expected, "{}", file);
+        }
+    }
+
+    #[test]
+    fn test_load_gzip_config_by_inner_extension() {
+        let dir = temp_dir("gzip");
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(br#"{"name":"app","debug":false,"server":{"host":"localhost","port":1}}"#)
+            .unwrap();
+        fs::write(dir.join("app.json.gz"), encoder.finish().unwrap()).unwrap();
+
+        let config: Config = load_config(dir.join("app.json.gz")).unwrap();
+        assert_eq!(config.server.port, 1);
+    }
+
+    #[test]
+    fn test_substituted_values_are_not_parsed() {
+        unsafe {
+            env::set_var("TEST_CONFIG_SECRET", r#"a"b\c"#);
+        }
+        let dir = temp_dir("escape");
+        fs::write(
+            dir.join("app.toml"),
+            "name = \"${TEST_CONFIG_SECRET}\"\ndebug = false\n\n[server]\nhost = 'h'\nport = 1\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("app.json"),
+            r#"{"name":"${TEST_CONFIG_SECRET}","debug":false,"server":{"host":"h","port":1}}"#,
+        )
+        .unwrap();
+
+        for file in ["app.toml", "app.json"] {
+            let config: Config = load_config(dir.join(file)).unwrap();
+            assert_eq!(config.name, r#"a"b\c"#, "{}", file);
+        }
+    }
+
+    #[test]
+    fn test_partial_substitution_stays_string() {
+        let dir = temp_dir("partial");
+        fs::write(
+            dir.join("app.toml"),
+            "name = \"${TEST_CONFIG_NUM:1}${TEST_CONFIG_NUM:1}\"\ndebug = false\n\n[server]\nhost = \"h:${TEST_CONFIG_NUM:1}\"\nport = 1\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("app.json"),
+            r#"{"name":"${TEST_CONFIG_NUM:1}${TEST_CONFIG_NUM:1}","debug":false,"server":{"host":"h:${TEST_CONFIG_NUM:1}","port":1}}"#,
+        )
+        .unwrap();
+
+        for file in ["app.toml", "app.json"] {
+            let config: Config = load_config(dir.join(file)).unwrap();
+            assert_eq!(config.name, "11", "{}", file);
+            assert_eq!(config.server.host, "h:1", "{}", file);
+        }
+    }
+
+    #[test]
+    fn test_type_error_reports_field() {
+        let dir = temp_dir("type_error");
+        fs::write(
+            dir.join("app.json"),
+            r#"{"name":"${TEST_CONFIG_NAME:app}","debug":false,"server":{"host":"h","port":"x"}}"#,
+        )
+        .unwrap();
+
+        let err = load_config::<Config, _>(dir.join("app.json")).unwrap_err();
+        assert!(err.message.contains("at server.port"), "{}", err.message);
+    }
+
+    #[test]
+    fn test_unknown_extension() {
+        let err = load_config::<Config, _>("config/app.ini").unwrap_err();
+        assert!(err.message.contains("Unsupported config file format"));
+    }
+}
@@ -1,4 +1,6 @@
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+#[cfg(feature = "tz")]
+use chrono_tz::Tz;
 use serde::{self, Serializer};
 
 /// Trait for types that can be formatted with a date string.
@@ -31,6 +33,121 @@ impl DateFormattable for Option<NaiveDate> {
     }
 }
 
+impl DateFormattable for NaiveDateTime {
+    fn format_date(&self, fmt: &str) -> String {
+        self.format(fmt).to_string()
+    }
+    fn is_none(&self) -> bool {
+        false
+    }
+}
+
+impl DateFormattable for NaiveDate {
+    fn format_date(&self, fmt: &str) -> String {
+        self.format(fmt).to_string()
+    }
+    fn is_none(&self) -> bool {
+        false
+    }
+}
+
+impl DateFormattable for Option<DateTime<Utc>> {
+    fn format_date(&self, fmt: &str) -> String {
+        match self {
+            Some(dt) => dt.format(fmt).to_string(),
+            None => String::new(),
+        }
+    }
+    fn is_none(&self) -> bool {
+        self.is_none()
+    }
+}
+
+/// `time` 类型的格式化支持（需启用 `time` feature）
+///
+/// 格式字符串统一使用 strftime 语法（与 chrono 一致，如 `%Y-%m-%d`），内部转换为 `time` 的
+/// format description（如 `[year]-[month]-[day]`）。仅支持常用占位符：
+/// `%Y %y %m %d %e %H %I %M %S %p %b %B %a %A %j %%`，格式无法转换时输出空字符串。
+#[cfg(feature = "time")]
+mod time_support {
+    use super::DateFormattable;
+    use time::format_description;
+
+    /// 将 strftime 格式转换为 `time` 的 format description 语法
+    pub fn strftime_to_description(fmt: &str) -> Option<String> {
+        let mut result = String::with_capacity(fmt.len() * 2);
+        let mut chars = fmt.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '%' => {
+                    let component = match chars.next()? {
+                        'Y' => "[year]",
+                        'y' => "[year repr:last_two]",
+                        'm' => "[month]",
+                        'd' => "[day]",
+                        'e' => "[day padding:space]",
+                        'H' => "[hour]",
+                        'I' => "[hour repr:12]",
+                        'M' => "[minute]",
+                        'S' => "[second]",
+                        'p' => "[period]",
+                        'b' => "[month repr:short]",
+                        'B' => "[month repr:long]",
+                        'a' => "[weekday repr:short]",
+                        'A' => "[weekday repr:long]",
+                        'j' => "[ordinal]",
+                        '%' => "%",
+                        _ => return None,
+                    };
+                    result.push_str(component);
+                }
+                '[' => result.push_str("[["),
+                _ => result.push(c),
+            }
+        }
+        Some(result)
+    }
+
+    fn format_with<F>(fmt: &str, format: F) -> String
+    where
+        F: FnOnce(&[format_description::BorrowedFormatItem<'_>]) -> Option<String>,
+    {
+        strftime_to_description(fmt)
+            .and_then(|desc| {
+                let items = format_description::parse(&desc).ok()?;
+                format(&items)
+            })
+            .unwrap_or_default()
+    }
+
+    impl DateFormattable for Option<time::PrimitiveDateTime> {
+        fn format_date(&self, fmt: &str) -> String {
+            match self {
+                Some(dt) => format_with(fmt, |items| dt.format(items).ok()),
+                None => String::new(),
+            }
+        }
+        fn is_none(&self) -> bool {
+            self.is_none()
+        }
+    }
+
+    impl DateFormattable for Option<time::Date> {
+        fn format_date(&self, fmt: &str) -> String {
+            match self {
+                Some(d) => format_with(fmt, |items| d.format(items).ok()),
+                None => String::new(),
+            }
+        }
+        fn is_none(&self) -> bool {
+            self.is_none()
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+pub use time_support::strftime_to_description;
+
 pub fn serialize_with_custom_format<S, T>(
     date: &T,
     format: &str,
@@ -47,6 +164,27 @@ where
     }
 }
 
+/// 先将 UTC 时间转换到目标时区，再按格式序列化
+///
+/// 时区通常来自配置或请求头，因此以参数形式传入，配合 `serialize_with` 的包装函数使用。
+/// 需启用 `tz` feature。
+#[cfg(feature = "tz")]
+pub fn serialize_in_tz<S>(
+    date: &Option<DateTime<Utc>>,
+    tz: Tz,
+    format: &str,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let local = date.map(|dt| dt.with_timezone(&tz));
+    match local {
+        Some(dt) => serializer.serialize_str(&dt.format(format).to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
 macro_rules! define_format {
     ($name:ident, $format:expr) => {
         pub mod $name {
@@ -65,3 +203,113 @@ macro_rules! define_format {
 // 预定义一些常用格式
 define_format!(standard, "%Y-%m-%d %H:%M:%S");
 define_format!(date_only, "%Y-%m-%d");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[cfg(feature = "tz")]
+    #[test]
+    fn test_serialize_in_tz() {
+        use chrono::TimeZone;
+
+        fn shanghai<S: Serializer>(
+            date: &Option<DateTime<Utc>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serialize_in_tz(
+                date,
+                chrono_tz::Asia::Shanghai,
+                "%Y-%m-%d %H:%M:%S",
+                serializer,
+            )
+        }
+
+        #[derive(Serialize)]
+        struct Event {
+            #[serde(serialize_with = "shanghai")]
+            at: Option<DateTime<Utc>>,
+        }
+
+        let at = Utc.with_ymd_and_hms(2024, 1, 1, 16, 30, 0).unwrap();
+        let json = serde_json::to_string(&Event { at: Some(at) }).unwrap();
+        assert_eq!(json, r#"{"at":"2024-01-02 00:30:00"}"#);
+
+        let json = serde_json::to_string(&Event { at: None }).unwrap();
+        assert_eq!(json, r#"{"at":null}"#);
+    }
+
+    #[test]
+    fn test_non_option_types() {
+        #[derive(Serialize)]
+        struct Record {
+            #[serde(with = "standard")]
+            created: NaiveDateTime,
+            #[serde(with = "date_only")]
+            day: NaiveDate,
+            #[serde(with = "standard")]
+            updated: Option<NaiveDateTime>,
+        }
+
+        let day = NaiveDate::from_ymd_opt(2024, 3, 5).unwrap();
+        let created = day.and_hms_opt(8, 9, 10).unwrap();
+        let json = serde_json::to_string(&Record {
+            created,
+            day,
+            updated: None,
+        })
+        .unwrap();
+        assert_eq!(
+            json,
+            r#"{"created":"2024-03-05 08:09:10","day":"2024-03-05","updated":null}"#
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_time_types_round_trip() {
+        use time::{Date, Month, PrimitiveDateTime, Time, format_description};
+
+        #[derive(Serialize)]
+        struct Record {
+            #[serde(with = "standard")]
+            created: Option<PrimitiveDateTime>,
+            #[serde(with = "date_only")]
+            day: Option<Date>,
+        }
+
+        let date = Date::from_calendar_date(2024, Month::March, 5).unwrap();
+        let created = PrimitiveDateTime::new(date, Time::from_hms(8, 9, 10).unwrap());
+        let json = serde_json::to_value(Record {
+            created: Some(created),
+            day: Some(date),
+        })
+        .unwrap();
+        assert_eq!(json["created"], "2024-03-05 08:09:10");
+        assert_eq!(json["day"], "2024-03-05");
+
+        let desc = strftime_to_description("%Y-%m-%d %H:%M:%S").unwrap();
+        let items = format_description::parse(&desc).unwrap();
+        let parsed = PrimitiveDateTime::parse(json["created"].as_str().unwrap(), &items).unwrap();
+        assert_eq!(parsed, created);
+
+        let json = serde_json::to_value(Record {
+            created: None,
+            day: None,
+        })
+        .unwrap();
+        assert!(json["created"].is_null());
+        assert!(json["day"].is_null());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn test_strftime_to_description() {
+        assert_eq!(
+            strftime_to_description("%d/%m/%Y [%H]").unwrap(),
+            "[day]/[month]/[year] [[[hour]]"
+        );
+        assert!(strftime_to_description("%Q").is_none());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod config;
 pub mod date_format;
 pub mod uid;
 pub mod yaml;
\ No newline at end of file
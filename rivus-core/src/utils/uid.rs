@@ -1,4 +1,5 @@
 use rand::Rng;
+use std::collections::HashSet;
 use crate::error::Error;
 
 /// Convert a custom Base64-like string to u64.
@@ -78,6 +79,36 @@ pub fn generate_api_key(length: usize) -> String {
         .collect()
 }
 
+/// Generate `count` distinct API Keys of specified length.
+///
+/// Keys colliding within the batch are regenerated, so exactly `count` keys are returned.
+///
+/// # Panics
+///
+/// Panics if `length` is too short to produce `count` distinct keys.
+pub fn generate_api_keys(count: usize, length: usize) -> Vec<String> {
+    let capacity = u32::try_from(length)
+        .ok()
+        .and_then(|len| 62u128.checked_pow(len))
+        .unwrap_or(u128::MAX);
+    assert!(
+        count as u128 <= capacity,
+        "Cannot generate {} distinct keys of length {}",
+        count,
+        length
+    );
+
+    let mut seen = HashSet::with_capacity(count);
+    let mut keys = Vec::with_capacity(count);
+    while keys.len() < count {
+        let key = generate_api_key(length);
+        if seen.insert(key.clone()) {
+            keys.push(key);
+        }
+    }
+    keys
+}
+
 #[cfg(test)]
 mod tests {
     // 引入模块中的所有内容
@@ -105,4 +136,18 @@ mod tests {
         // 检查两次生成的 API Key 是否不同（极低概率下可能相同，但几乎可以忽略）
         assert_ne!(key1, key2, "两次生成的 API Key 不应相同");
     }
+
+    #[test]
+    fn test_api_keys_distinct() {
+        let keys = generate_api_keys(100, 16);
+        assert_eq!(keys.len(), 100);
+        assert!(keys.iter().all(|k| k.len() == 16));
+        let unique: HashSet<_> = keys.iter().collect();
+        assert_eq!(unique.len(), 100, "批量生成的 API Key 不应重复");
+
+        // 短长度下碰撞频繁，仍应返回指定数量的不同 Key
+        let keys = generate_api_keys(62, 1);
+        let unique: HashSet<_> = keys.iter().collect();
+        assert_eq!(unique.len(), 62);
+    }
 }
@@ -1,46 +1,361 @@
-//! YAML 配置加载器，支持环境变量替换
+//! YAML 配置加载器，支持环境变量替换与 `!include` 引入子文件
 
 use dotenvy::dotenv;
+use flate2::read::GzDecoder;
 use regex::Regex;
 use serde::de::DeserializeOwned;
+use serde_yaml::Value;
+use std::collections::BTreeMap;
 use std::env;
 use std::fs;
-use std::path::Path;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
+use crate::code::Code;
 use crate::error::Error;
 
+const INCLUDE_TAG: &str = "!include";
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// 变量值的来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VarSource {
+    /// 来自同名环境变量
+    Env,
+    /// 来自 `NAME_FILE` 指向的文件
+    File,
+    /// 使用了占位符中的默认值
+    Default,
+    /// 未设置且无默认值，替换为空字符串
+    Missing,
+}
+
+/// 环境变量替换报告，仅记录变量名与取值来源，不包含变量值
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubstitutionReport {
+    vars: BTreeMap<String, VarSource>,
+}
+
+impl SubstitutionReport {
+    /// 所有被引用的变量及其来源（按变量名排序）
+    pub fn vars(&self) -> &BTreeMap<String, VarSource> {
+        &self.vars
+    }
+
+    /// 查询某个变量的来源，未被引用时返回 None
+    pub fn source(&self, name: &str) -> Option<VarSource> {
+        self.vars.get(name).copied()
+    }
+
+    fn record(&mut self, name: &str, source: VarSource) {
+        self.vars.insert(name.to_string(), source);
+    }
+}
+
+/// 加载过程中的上下文：`!include` 调用栈与变量替换报告
+#[derive(Default)]
+struct LoadContext {
+    stack: Vec<PathBuf>,
+    report: SubstitutionReport,
+}
+
 /// 替换 YAML 中的环境变量占位符
-fn replace_vars(yaml_content: &str) -> Result<String, Error> {
+pub(crate) fn replace_vars(yaml_content: &str) -> Result<String, Error> {
+    replace_vars_with_report(yaml_content, &mut SubstitutionReport::default())
+}
+
+/// 替换 YAML 中的环境变量占位符，并记录每个变量的来源
+fn replace_vars_with_report(
+    yaml_content: &str,
+    report: &mut SubstitutionReport,
+) -> Result<String, Error> {
     // 忽略 dotenv 加载错误（例如生产环境可能没有 .env 文件）
     let _ = dotenv();
 
+    let re = var_regex();
+    let mut result = String::with_capacity(yaml_content.len());
+    let mut last = 0;
+    for caps in re.captures_iter(yaml_content) {
+        let placeholder = caps.get(0).expect("capture group 0 always exists");
+        let default = caps.get(2).map(|m| m.as_str());
+
+        result.push_str(&yaml_content[last..placeholder.start()]);
+        let (value, source) = resolve_var(&caps[1], default)?;
+        report.record(&caps[1], source);
+        result.push_str(&value);
+        last = placeholder.end();
+    }
+    result.push_str(&yaml_content[last..]);
+
+    Ok(result)
+}
+
+fn var_regex() -> &'static Regex {
     static VAR_REGEX: OnceLock<Regex> = OnceLock::new();
-    let re = VAR_REGEX.get_or_init(|| {
+    VAR_REGEX.get_or_init(|| {
         Regex::new(r"\$\{([a-zA-Z0-9_]+)(?::([^\}]*))?\}").expect("Invalid regex pattern")
-    });
-
-    let result = re.replace_all(yaml_content, |caps: &regex::Captures| {
-        let var_name = &caps[1];
-        let default = caps.get(2).map(|m| m.as_str());
+    })
+}
 
-        match env::var(var_name) {
-            Ok(val) => val,
-            Err(_) => default.unwrap_or("").to_string(),
-        }
-    });
+/// 字符串是否恰好为单个 `${...}` 占位符
+pub(crate) fn is_single_placeholder(s: &str) -> bool {
+    var_regex()
+        .find(s)
+        .is_some_and(|m| m.start() == 0 && m.end() == s.len())
+}
 
-    Ok(result.into_owned())
+/// 解析单个变量的值
+///
+/// 优先读取环境变量 `NAME`；未设置时若存在 `NAME_FILE`（Docker/K8s secrets 约定），
+/// 则读取其指向文件的内容并去除首尾空白；否则使用默认值。
+fn resolve_var(var_name: &str, default: Option<&str>) -> Result<(String, VarSource), Error> {
+    if let Ok(val) = env::var(var_name) {
+        return Ok((val, VarSource::Env));
+    }
+    let file_var = format!("{}_FILE", var_name);
+    if let Ok(file) = env::var(&file_var) {
+        let content = fs::read_to_string(&file).map_err(|e| {
+            Error::new(Code::InternalServerError.as_i32())
+                .with_message(format!("Failed to read {} ({}): {}", file_var, file, e))
+        })?;
+        return Ok((content.trim().to_string(), VarSource::File));
+    }
+    match default {
+        Some(default) => Ok((default.to_string(), VarSource::Default)),
+        None => Ok((String::new(), VarSource::Missing)),
+    }
 }
 
 /// 从文件加载 YAML 配置
+///
+/// 支持 gzip 压缩的文件（如 `app.yaml.gz`），读取时自动解压。
+/// 值可以使用 `!include path` 引入其他 YAML 文件，路径相对于当前文件所在目录解析。
+/// 被引入的文件同样会进行环境变量替换，并可继续引入其他文件；循环引入会返回错误。
 #[allow(dead_code)]
 pub fn load_from_file<T: DeserializeOwned, P: AsRef<Path>>(path: P) -> Result<T, Error> {
-    let content = fs::read_to_string(path)?;
-    let replaced = replace_vars(&content)?;
-    let data = serde_yaml::from_str(&replaced)?;
+    let (data, _) = load_from_file_with_report(path)?;
     Ok(data)
 }
 
+/// 从文件加载 YAML 配置，同时返回环境变量替换报告
+///
+/// 报告列出配置（含 `!include` 引入的文件）引用的全部变量名及其来源，可用于审计，
+/// 不包含任何变量值。
+#[allow(dead_code)]
+pub fn load_from_file_with_report<T: DeserializeOwned, P: AsRef<Path>>(
+    path: P,
+) -> Result<(T, SubstitutionReport), Error> {
+    let mut ctx = LoadContext::default();
+    let data = load_root(path.as_ref(), &mut ctx, |_| {})?;
+    Ok((data, ctx.report))
+}
+
+/// 从文件加载 YAML 配置，并用带前缀的环境变量覆盖任意字段
+///
+/// 例如 `prefix = "APP"`、`separator = "__"` 时，`APP__SERVER__PORT=9090` 会覆盖 `server.port`。
+/// 详见 [`apply_env_overrides`]。
+#[allow(dead_code)]
+pub fn load_from_file_with_env<T: DeserializeOwned, P: AsRef<Path>>(
+    path: P,
+    prefix: &str,
+    separator: &str,
+) -> Result<T, Error> {
+    let mut ctx = LoadContext::default();
+    load_root(path.as_ref(), &mut ctx, |value| {
+        apply_env_overrides(value, prefix, separator)
+    })
+}
+
+/// 从字符串加载 YAML 配置，并用带前缀的环境变量覆盖任意字段
+#[allow(dead_code)]
+pub fn load_from_str_with_env<T: DeserializeOwned>(
+    yaml_content: &str,
+    prefix: &str,
+    separator: &str,
+) -> Result<T, Error> {
+    let replaced = replace_vars(yaml_content)?;
+    let mut value: Value = serde_yaml::from_str(&replaced)?;
+    let original = value.clone();
+    apply_env_overrides(&mut value, prefix, separator);
+    if value == original {
+        return Ok(serde_yaml::from_str(&replaced)?);
+    }
+    deserialize_value(value, None)
+}
+
+/// 将 `{prefix}{separator}A{separator}B` 形式的环境变量覆盖到 YAML 值的 `a.b` 字段
+///
+/// 路径段统一转为小写；不存在的中间层级会自动创建，但路径途经标量或序列时该变量会被忽略，
+/// 不会覆盖原有值。原字段为字符串时保持字符串，否则环境变量的值按 YAML 标量解析，
+/// 因此 `9090`、`true` 会得到数字和布尔值，无法解析时按字符串处理。
+/// 名称或值不是合法 UTF-8 的环境变量会被忽略。
+pub fn apply_env_overrides(value: &mut Value, prefix: &str, separator: &str) {
+    let prefix = format!("{}{}", prefix, separator);
+    let mut overrides: Vec<_> = env::vars_os()
+        .filter_map(|(key, val)| {
+            let (key, val) = (key.into_string().ok()?, val.into_string().ok()?);
+            let path = key.strip_prefix(&prefix)?;
+            let segments: Vec<String> = path
+                .split(separator)
+                .map(|segment| segment.to_lowercase())
+                .collect();
+            if segments.iter().any(String::is_empty) {
+                return None;
+            }
+            Some((segments, val))
+        })
+        .collect();
+    // 按路径排序，保证覆盖顺序稳定
+    overrides.sort();
+
+    for (segments, val) in overrides {
+        set_path(value, &segments, &val);
+    }
+}
+
+/// 将环境变量的值转换为 YAML 值：原值为字符串或 `raw` 为空时保持字符串，否则按 YAML 标量解析
+pub(crate) fn parse_env_value(existing: &Value, raw: &str) -> Value {
+    if raw.is_empty() || existing.is_string() {
+        return Value::String(raw.to_string());
+    }
+    match serde_yaml::from_str::<Value>(raw) {
+        Ok(value @ (Value::Bool(_) | Value::Number(_) | Value::String(_) | Value::Null)) => value,
+        _ => Value::String(raw.to_string()),
+    }
+}
+
+fn set_path(value: &mut Value, segments: &[String], raw: &str) {
+    let Some((first, rest)) = segments.split_first() else {
+        *value = parse_env_value(value, raw);
+        return;
+    };
+    if value.is_null() {
+        *value = Value::Mapping(Default::default());
+    }
+    // 途经标量或序列时跳过，避免整体替换原有值
+    if let Value::Mapping(map) = value {
+        let key = Value::String(first.clone());
+        let child = map.entry(key).or_insert(Value::Null);
+        set_path(child, rest, raw);
+    }
+}
+
+/// 加载根配置文件并反序列化，`customize` 可在反序列化前修改 YAML 值
+///
+/// 文档不含 `!include` 且 `customize` 未做修改时直接从文本反序列化，以保留错误的行列位置；
+/// 否则从展开后的值反序列化，错误中附带文件路径与出错字段。
+fn load_root<T: DeserializeOwned>(
+    path: &Path,
+    ctx: &mut LoadContext,
+    customize: impl FnOnce(&mut Value),
+) -> Result<T, Error> {
+    let (path, replaced, mut value) = read_document(path, ctx)?;
+    let has_include = contains_include(&value);
+    if has_include {
+        // 先展开 `!include`，`customize` 才能修改被引入文件中的字段
+        let base = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        ctx.stack.push(path.clone());
+        resolve_includes(&mut value, &base, ctx)?;
+        ctx.stack.pop();
+    }
+
+    let original = (!has_include).then(|| value.clone());
+    customize(&mut value);
+    if original.is_some_and(|original| original == value) {
+        return Ok(serde_yaml::from_str(&replaced)?);
+    }
+
+    deserialize_value(value, Some(&path))
+}
+
+/// 从 YAML 值反序列化，错误中附带出错字段路径（及文件路径）
+fn deserialize_value<T: DeserializeOwned>(value: Value, path: Option<&Path>) -> Result<T, Error> {
+    serde_path_to_error::deserialize(value).map_err(|e| {
+        let message = match path {
+            Some(path) => format!("{}: {} at {}", path.display(), e.inner(), e.path()),
+            None => format!("{} at {}", e.inner(), e.path()),
+        };
+        Error::new(Code::InternalServerError.as_i32()).with_message(message)
+    })
+}
+
+/// 读取文件并解析为 YAML 值，递归展开其中的 `!include`
+fn load_value_from_file(path: &Path, ctx: &mut LoadContext) -> Result<Value, Error> {
+    let (path, _, mut value) = read_document(path, ctx)?;
+
+    let base = path.parent().map(Path::to_path_buf).unwrap_or_default();
+    ctx.stack.push(path);
+    resolve_includes(&mut value, &base, ctx)?;
+    ctx.stack.pop();
+    Ok(value)
+}
+
+/// 读取文件、替换环境变量并解析为 YAML 值（不展开 `!include`）
+///
+/// 返回规范化后的路径、替换后的文本与解析结果。
+fn read_document(path: &Path, ctx: &mut LoadContext) -> Result<(PathBuf, String, Value), Error> {
+    let path = fs::canonicalize(path)?;
+    if ctx.stack.contains(&path) {
+        return Err(Error::new(Code::InternalServerError.as_i32())
+            .with_message(format!("Circular YAML include: {}", path.display())));
+    }
+
+    let content = read_config_file(&path)?;
+    let replaced = replace_vars_with_report(&content, &mut ctx.report)?;
+    let value: Value = serde_yaml::from_str(&replaced)?;
+    Ok((path, replaced, value))
+}
+
+/// 判断 YAML 值中是否存在 `!include` 节点
+fn contains_include(value: &Value) -> bool {
+    match value {
+        Value::Tagged(tagged) => tagged.tag == INCLUDE_TAG || contains_include(&tagged.value),
+        Value::Sequence(items) => items.iter().any(contains_include),
+        Value::Mapping(map) => map.values().any(contains_include),
+        _ => false,
+    }
+}
+
+/// 读取配置文件内容，gzip 压缩的文件（按魔数识别）会先解压
+pub(crate) fn read_config_file(path: &Path) -> Result<String, Error> {
+    let bytes = fs::read(path)?;
+    let content = if bytes.starts_with(&GZIP_MAGIC) {
+        let mut content = String::new();
+        GzDecoder::new(bytes.as_slice()).read_to_string(&mut content)?;
+        content
+    } else {
+        String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+    };
+    Ok(content)
+}
+
+/// 将 `!include path` 节点替换为对应文件的内容
+fn resolve_includes(value: &mut Value, base: &Path, ctx: &mut LoadContext) -> Result<(), Error> {
+    match value {
+        Value::Tagged(tagged) if tagged.tag == INCLUDE_TAG => {
+            let Value::String(include) = &tagged.value else {
+                return Err(Error::new(Code::InternalServerError.as_i32())
+                    .with_message("The !include tag expects a file path"));
+            };
+            let included = load_value_from_file(&base.join(include), ctx)?;
+            *value = included;
+        }
+        Value::Tagged(tagged) => resolve_includes(&mut tagged.value, base, ctx)?,
+        Value::Sequence(items) => {
+            for item in items {
+                resolve_includes(item, base, ctx)?;
+            }
+        }
+        Value::Mapping(map) => {
+            for (_, item) in map.iter_mut() {
+                resolve_includes(item, base, ctx)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
 /// 从字符串加载 YAML 配置
 #[allow(dead_code)]
 pub fn load_from_str<T: DeserializeOwned>(yaml_content: &str) -> Result<T, Error> {
@@ -58,7 +373,23 @@ macro_rules! include_yaml {
     };
 }
 
+/// 编译时嵌入 YAML 文件，首次访问时解析并缓存，返回 `&'static T`
+///
+/// 嵌入内容在编译期已固定，解析失败视为程序缺陷：首次访问时会 panic 并给出文件路径与错误信息。
+/// 需要自行处理错误时请使用 `include_yaml!`。
+#[macro_export]
+macro_rules! include_yaml_static {
+    ($path:expr, $t:ty) => {{
+        static VALUE: ::std::sync::OnceLock<$t> = ::std::sync::OnceLock::new();
+        VALUE.get_or_init(|| {
+            $crate::utils::yaml::load_from_str::<$t>(include_str!($path))
+                .unwrap_or_else(|e| panic!("Invalid embedded YAML {}: {}", $path, e))
+        })
+    }};
+}
+
 pub use include_yaml;
+pub use include_yaml_static;
 
 #[cfg(test)]
 mod tests {
@@ -99,6 +430,36 @@ mod tests {
         assert_eq!(output, "key: lower_value");
     }
 
+    #[test]
+    fn test_replace_vars_from_secret_file() {
+        let dir = temp_dir("secret");
+        let secret = dir.join("db_password");
+        fs::write(&secret, "s3cret\n").unwrap();
+        unsafe {
+            env::set_var("TEST_SECRET_PASSWORD_FILE", &secret);
+        }
+
+        let input = "password: ${TEST_SECRET_PASSWORD:fallback}";
+        let output = replace_vars(input).unwrap();
+        assert_eq!(output, "password: s3cret");
+    }
+
+    #[test]
+    fn test_replace_vars_missing_secret_file() {
+        unsafe {
+            env::set_var("TEST_SECRET_MISSING_FILE", "/nonexistent/rivus/secret");
+        }
+
+        let input = "password: ${TEST_SECRET_MISSING}";
+        let err = replace_vars(input).unwrap_err();
+        assert!(
+            err.message
+                .contains("TEST_SECRET_MISSING_FILE (/nonexistent/rivus/secret)"),
+            "{}",
+            err.message
+        );
+    }
+
     #[test]
     fn test_load_from_str() {
         #[derive(Deserialize, Debug, PartialEq)]
@@ -119,4 +480,289 @@ mod tests {
         assert_eq!(config.host, "localhost");
         assert_eq!(config.port, 8080);
     }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("rivus_yaml_{}", name));
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_load_from_file_with_include() {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Database {
+            url: String,
+            pool: u32,
+        }
+
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Config {
+            name: String,
+            database: Database,
+        }
+
+        unsafe {
+            env::set_var("TEST_INCLUDE_DB_URL", "postgres://db");
+        }
+        let dir = temp_dir("include");
+        fs::create_dir_all(dir.join("parts")).unwrap();
+        fs::write(
+            dir.join("parts/db.yaml"),
+            "url: ${TEST_INCLUDE_DB_URL}\npool: ${TEST_INCLUDE_DB_POOL:5}\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.join("app.yaml"),
+            "name: app\ndatabase: !include parts/db.yaml\n",
+        )
+        .unwrap();
+
+        let config: Config = load_from_file(dir.join("app.yaml")).unwrap();
+        assert_eq!(config.name, "app");
+        assert_eq!(config.database.url, "postgres://db");
+        assert_eq!(config.database.pool, 5);
+    }
+
+    #[test]
+    fn test_load_from_file_include_cycle() {
+        let dir = temp_dir("include_cycle");
+        fs::write(dir.join("a.yaml"), "b: !include b.yaml\n").unwrap();
+        fs::write(dir.join("b.yaml"), "a: !include a.yaml\n").unwrap();
+
+        let err = load_from_file::<Value, _>(dir.join("a.yaml")).unwrap_err();
+        assert!(err.message.contains("Circular YAML include"));
+    }
+
+    #[test]
+    fn test_load_from_file_error_location() {
+        #[derive(Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Server {
+            port: u16,
+        }
+
+        #[derive(Deserialize, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            name: String,
+            server: Server,
+        }
+
+        let dir = temp_dir("error_location");
+        fs::write(dir.join("plain.yaml"), "name: app\nserver:\n  port: abc\n").unwrap();
+        let err = load_from_file::<Config, _>(dir.join("plain.yaml")).unwrap_err();
+        assert!(err.message.contains("line 3 column 9"), "{}", err.message);
+
+        // 含 `!include` 时无法定位行列，改为给出文件路径与字段路径
+        fs::write(dir.join("server.yaml"), "port: abc\n").unwrap();
+        fs::write(
+            dir.join("app.yaml"),
+            "name: app\nserver: !include server.yaml\n",
+        )
+        .unwrap();
+        let err = load_from_file::<Config, _>(dir.join("app.yaml")).unwrap_err();
+        assert!(err.message.contains("app.yaml"), "{}", err.message);
+        assert!(err.message.contains("at server.port"), "{}", err.message);
+    }
+
+    #[test]
+    fn test_load_from_file_with_report() {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Config {
+            host: String,
+            port: u16,
+            password: String,
+            token: String,
+        }
+
+        let dir = temp_dir("report");
+        let secret = dir.join("password");
+        fs::write(&secret, "hunter2").unwrap();
+        unsafe {
+            env::set_var("TEST_REPORT_HOST", "db.internal");
+            env::set_var("TEST_REPORT_PASSWORD_FILE", &secret);
+        }
+        fs::write(
+            dir.join("app.yaml"),
+            "host: ${TEST_REPORT_HOST}\nport: ${TEST_REPORT_PORT:5432}\n\
+             password: ${TEST_REPORT_PASSWORD}\ntoken: \"${TEST_REPORT_TOKEN}\"\n",
+        )
+        .unwrap();
+
+        let (config, report): (Config, _) =
+            load_from_file_with_report(dir.join("app.yaml")).unwrap();
+        assert_eq!(config.port, 5432);
+        assert_eq!(config.password, "hunter2");
+
+        assert_eq!(report.vars().len(), 4);
+        assert_eq!(report.source("TEST_REPORT_HOST"), Some(VarSource::Env));
+        assert_eq!(report.source("TEST_REPORT_PORT"), Some(VarSource::Default));
+        assert_eq!(report.source("TEST_REPORT_PASSWORD"), Some(VarSource::File));
+        assert_eq!(report.source("TEST_REPORT_TOKEN"), Some(VarSource::Missing));
+        assert!(!format!("{:?}", report).contains("hunter2"));
+    }
+
+    #[test]
+    fn test_load_from_gzip_file() {
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Config {
+            host: String,
+            port: u16,
+        }
+
+        let dir = temp_dir("gzip");
+        let path = dir.join("app.yaml.gz");
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(b"host: example.com\nport: ${TEST_GZIP_PORT:7000}\n")
+            .unwrap();
+        fs::write(&path, encoder.finish().unwrap()).unwrap();
+
+        let config: Config = load_from_file(&path).unwrap();
+        assert_eq!(config.host, "example.com");
+        assert_eq!(config.port, 7000);
+    }
+
+    #[test]
+    fn test_load_from_corrupt_gzip_file() {
+        let dir = temp_dir("gzip_corrupt");
+        let path = dir.join("app.yaml.gz");
+        fs::write(&path, [0x1f, 0x8b, 0x00, 0x01, 0x02]).unwrap();
+
+        assert!(load_from_file::<Value, _>(&path).is_err());
+    }
+
+    #[test]
+    fn test_load_with_env_overrides() {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Server {
+            host: String,
+            port: u16,
+            tls: bool,
+        }
+
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Config {
+            name: String,
+            server: Server,
+            tags: Option<Vec<String>>,
+        }
+
+        unsafe {
+            env::set_var("TESTOVR__SERVER__PORT", "9090");
+            env::set_var("TESTOVR__SERVER__TLS", "true");
+            env::set_var("TESTOVR__NAME", "12345");
+        }
+        let yaml = r#"
+        name: app
+        server:
+          host: localhost
+          port: 8080
+          tls: false
+        "#;
+
+        let config: Config = load_from_str_with_env(yaml, "TESTOVR", "__").unwrap();
+        assert_eq!(config.server.port, 9090);
+        assert!(config.server.tls);
+        assert_eq!(config.server.host, "localhost");
+        // 原字段为字符串时，数字形式的值保持为字符串
+        assert_eq!(config.name, "12345");
+        assert_eq!(config.tags, None);
+    }
+
+    #[test]
+    fn test_env_overrides_apply_to_included_files() {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Database {
+            pool: u32,
+        }
+
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Config {
+            name: String,
+            database: Database,
+        }
+
+        unsafe {
+            env::set_var("TESTINCOVR__DATABASE__POOL", "9");
+        }
+        let dir = temp_dir("include_overrides");
+        fs::write(dir.join("db.yaml"), "pool: 1\n").unwrap();
+        fs::write(
+            dir.join("app.yaml"),
+            "name: app\ndatabase: !include db.yaml\n",
+        )
+        .unwrap();
+
+        let config: Config =
+            load_from_file_with_env(dir.join("app.yaml"), "TESTINCOVR", "__").unwrap();
+        assert_eq!(config.database.pool, 9);
+        assert_eq!(config.name, "app");
+    }
+
+    #[test]
+    fn test_env_overrides_create_nested_keys() {
+        unsafe {
+            env::set_var("TESTNEW_DB_POOL_MAX", "20");
+        }
+        let mut value: Value = serde_yaml::from_str("name: app").unwrap();
+        apply_env_overrides(&mut value, "TESTNEW", "_");
+        assert_eq!(value["db"]["pool"]["max"], Value::from(20));
+        assert_eq!(value["name"], Value::from("app"));
+    }
+
+    #[test]
+    fn test_env_overrides_skip_non_mapping_parents() {
+        unsafe {
+            env::set_var("TESTSKIP__TAGS__0", "x");
+            env::set_var("TESTSKIP__NAME__FIRST", "x");
+        }
+        let mut value: Value = serde_yaml::from_str("name: app\ntags: [a, b]").unwrap();
+        apply_env_overrides(&mut value, "TESTSKIP", "__");
+        assert_eq!(
+            value["tags"],
+            serde_yaml::from_str::<Value>("[a, b]").unwrap()
+        );
+        assert_eq!(value["name"], Value::from("app"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_env_overrides_ignore_non_utf8_vars() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        unsafe {
+            env::set_var("TESTUTF8__BAD", OsStr::from_bytes(b"\xff\xfe"));
+            env::set_var("TESTUTF8__GOOD", "ok");
+        }
+        let mut value: Value = serde_yaml::from_str("bad: keep").unwrap();
+        apply_env_overrides(&mut value, "TESTUTF8", "__");
+        assert_eq!(value["bad"], Value::from("keep"));
+        assert_eq!(value["good"], Value::from("ok"));
+    }
+
+    #[test]
+    fn test_include_yaml_static() {
+        #[derive(Deserialize, Debug, PartialEq)]
+        struct Config {
+            name: String,
+            port: u16,
+        }
+
+        fn config() -> &'static Config {
+            include_yaml_static!("../../tests/fixtures/static.yaml", Config)
+        }
+
+        assert_eq!(config().name, "rivus");
+        assert_eq!(config().port, 9000);
+        assert!(std::ptr::eq(config(), config()));
+    }
 }
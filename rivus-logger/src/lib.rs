@@ -1,11 +1,27 @@
+mod messages;
+mod recent;
+mod span_fields;
+mod stats;
+
 use log::error;
+pub use messages::LogLocale;
+use messages::Message;
+use recent::{RecentLogs, RecentLogsWriter};
+use span_fields::{SpanFieldsFormat, SpanFieldsLayer};
+use stats::CountingWriter;
+pub use stats::{LoggerStats, logger_stats};
+use std::fmt::Display;
 use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock, RwLock, mpsc};
 use std::time::Duration;
-use tracing_appender::non_blocking::WorkerGuard;
+use tracing::level_filters::ParseLevelFilterError;
+use tracing_appender::non_blocking::{NonBlockingBuilder, WorkerGuard};
 use tracing_subscriber::{
     Layer, Registry,
+    filter::LevelFilter,
     fmt::{self, time::ChronoLocal},
     layer::SubscriberExt,
+    reload,
     util::SubscriberInitExt,
 };
 
@@ -14,6 +30,15 @@ const DEFAULT_LOG_DIR: &str = "./logs";
 const DEFAULT_FILE_PREFIX: &str = "app.log";
 const DEFAULT_CLEANUP_INTERVAL: Duration = Duration::from_secs(3600);
 
+/// 全局日志级别的重载句柄，在 `init` 时设置
+static LEVEL_HANDLE: OnceLock<reload::Handle<LevelFilter, Registry>> = OnceLock::new();
+/// 当前生效的日志配置快照，在 `init` 时设置
+static CURRENT_CONFIG: OnceLock<RwLock<LoggerSnapshot>> = OnceLock::new();
+/// 文件输出的异步写入器守卫，由 `flush_logs` 或 `LoggerGuard` drop 时释放
+static WORKER_GUARD: Mutex<Option<WorkerGuard>> = Mutex::new(None);
+/// 内存中的最近日志缓冲区，启用 `in_memory_buffer` 时在 `init` 中设置
+static RECENT_LOGS: OnceLock<Arc<RecentLogs>> = OnceLock::new();
+
 /// 日志配置构建器
 ///
 /// 用于配置和初始化日志系统，支持控制台输出和文件滚动输出，
@@ -35,6 +60,249 @@ pub struct LoggerConfig {
     max_files: Option<i16>,
     /// 清理任务检查间隔
     cleanup_interval: Duration,
+    /// 作为每行日志前缀输出的 span 字段
+    span_fields: Vec<String>,
+    /// 内存中保留的最近日志条数
+    in_memory_buffer: Option<usize>,
+    /// 日志组件自身提示信息的语言
+    locale: LogLocale,
+    /// 异步文件写入器的缓冲行数上限
+    buffered_lines_limit: Option<usize>,
+    /// 额外的日志保留规则（用于同目录下的其他日志流）
+    retention_rules: Vec<RetentionRule>,
+    /// 是否优先在 tokio 运行时上执行清理任务
+    #[cfg(feature = "tokio")]
+    async_cleanup: bool,
+    /// 收到 SIGHUP 时重新读取日志级别的配置文件
+    #[cfg(all(unix, feature = "signal"))]
+    level_file: Option<PathBuf>,
+}
+
+/// 日志保留规则：同一目录下名为 `prefix` 或以 `prefix.` 开头的文件最多保留 `max_files` 个
+///
+/// 前缀按 `.` 分隔匹配，`app` 不会匹配 `app-access.2023-10-01`。存在更具体的规则时
+/// （如 `app` 与 `app.access`），属于 `app.access` 的文件只按该规则清理。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetentionRule {
+    /// 日志文件前缀
+    pub prefix: String,
+    /// 保留的最大文件数量，负数按 0 处理（仅保留最新文件）
+    pub max_files: i16,
+    /// 文件最长保留时间（按修改时间），超过即删除；最新文件始终保留
+    pub max_age: Option<Duration>,
+}
+
+impl RetentionRule {
+    /// 创建按数量保留的规则
+    pub fn new(prefix: impl Into<String>, max_files: i16) -> Self {
+        Self {
+            prefix: prefix.into(),
+            max_files,
+            max_age: None,
+        }
+    }
+
+    /// 额外删除修改时间早于 `max_age` 之前的文件
+    pub fn max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+}
+
+/// 当前生效的日志配置快照（只读副本）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoggerSnapshot {
+    /// 日志文件前缀
+    pub file_prefix: String,
+    /// 日志存储目录
+    pub log_dir: PathBuf,
+    /// 时间格式字符串
+    pub time_format: String,
+    /// 当前生效的日志级别（如 `info`）
+    pub level: String,
+    /// 是否启用控制台输出
+    pub console: bool,
+    /// 是否启用文件输出
+    pub file: bool,
+    /// 保留的最大日志文件数量
+    pub max_files: Option<i16>,
+    /// 清理任务检查间隔
+    pub cleanup_interval: Duration,
+    /// 作为日志前缀输出的 span 字段
+    pub span_fields: Vec<String>,
+    /// 内存中保留的最近日志条数
+    pub in_memory_buffer: Option<usize>,
+    /// 日志组件自身提示信息的语言
+    pub locale: LogLocale,
+    /// 异步文件写入器的缓冲行数上限
+    pub buffered_lines_limit: Option<usize>,
+    /// 额外的日志保留规则
+    pub retention_rules: Vec<RetentionRule>,
+}
+
+/// 运行时调整日志级别失败的原因
+#[derive(Debug)]
+pub enum LevelError {
+    /// 日志系统尚未通过 `init` 初始化
+    NotInitialized,
+    /// 无法解析的日志级别
+    Parse(ParseLevelFilterError),
+    /// 重载 subscriber 失败
+    Reload(reload::Error),
+    /// 读取级别配置文件失败
+    Io(std::io::Error),
+}
+
+impl Display for LevelError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LevelError::NotInitialized => write!(f, "logger is not initialized"),
+            LevelError::Parse(e) => write!(f, "invalid log level: {}", e),
+            LevelError::Reload(e) => write!(f, "failed to reload log level: {}", e),
+            LevelError::Io(e) => write!(f, "failed to read log level file: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for LevelError {}
+
+/// 获取当前生效的日志配置快照，未初始化时返回 None
+pub fn current_log_config() -> Option<LoggerSnapshot> {
+    let config = CURRENT_CONFIG.get()?;
+    let snapshot = config.read().unwrap_or_else(|e| e.into_inner());
+    Some(snapshot.clone())
+}
+
+/// 运行时调整全局日志级别
+///
+/// 解析失败时保留原有级别不变。
+pub fn set_log_level(level: &str) -> Result<(), LevelError> {
+    let handle = LEVEL_HANDLE.get().ok_or(LevelError::NotInitialized)?;
+    let filter = level.parse::<LevelFilter>().map_err(LevelError::Parse)?;
+    handle.reload(filter).map_err(LevelError::Reload)?;
+
+    if let Some(config) = CURRENT_CONFIG.get() {
+        let mut snapshot = config.write().unwrap_or_else(|e| e.into_inner());
+        snapshot.level = filter.to_string();
+    }
+    Ok(())
+}
+
+/// 从配置文件重新读取并应用日志级别
+///
+/// 文件内容为级别名（如 `debug`），也可写成 `level: debug` 或 `level = "debug"`，
+/// 空行与 `#` 开头的注释行会被忽略。读取或解析失败时记录警告并保留原有级别。
+pub fn reload_level_from_file(path: impl AsRef<Path>) -> Result<(), LevelError> {
+    let path = path.as_ref();
+    let result = std::fs::read_to_string(path)
+        .map_err(LevelError::Io)
+        .and_then(|content| set_log_level(parse_level_file(&content)));
+
+    if let Err(e) = &result {
+        let locale = current_log_config()
+            .map(|config| config.locale)
+            .unwrap_or_default();
+        tracing::warn!(
+            "{}",
+            Message::ReloadLevelFailed.render(locale, &[&path.display(), e])
+        );
+    }
+    result
+}
+
+/// 提取级别配置文件中的级别名
+fn parse_level_file(content: &str) -> &str {
+    let line = content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with('#'))
+        .unwrap_or_default();
+    let value = line
+        .strip_prefix("level")
+        .and_then(|rest| rest.trim_start().strip_prefix([':', '=']))
+        .unwrap_or(line);
+    value.trim().trim_matches(['"', '\''])
+}
+
+/// 获取内存中保留的最近日志（旧 → 新），未启用 `in_memory_buffer` 时返回空列表
+pub fn recent_logs() -> Vec<String> {
+    RECENT_LOGS
+        .get()
+        .map(|logs| logs.lines())
+        .unwrap_or_default()
+}
+
+/// 日志系统守卫
+///
+/// 持有清理任务句柄，drop 时刷新缓冲日志（见 `flush_logs`）并停止清理任务，
+/// 因此需要在程序运行期间一直持有。
+#[must_use = "dropping the guard flushes file logs and stops log cleanup"]
+pub struct LoggerGuard {
+    cleanup: Option<CleanupHandle>,
+}
+
+impl Drop for LoggerGuard {
+    fn drop(&mut self) {
+        if let Some(cleanup) = &self.cleanup {
+            cleanup.abort();
+        }
+        flush_logs();
+    }
+}
+
+/// 同步刷新异步文件写入器中缓冲的日志
+///
+/// 会阻塞直到缓冲区写入完成，适用于 `std::process::exit` 等不会执行析构的退出路径。
+/// 刷新后文件写入器即关闭，之后的日志不再写入文件，因此应在退出前调用。重复调用无副作用。
+pub fn flush_logs() {
+    let guard = WORKER_GUARD
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take();
+    drop(guard);
+}
+
+impl LoggerGuard {
+    /// 获取后台清理任务句柄（未启用清理时为 None）
+    pub fn cleanup_handle(&self) -> Option<&CleanupHandle> {
+        self.cleanup.as_ref()
+    }
+}
+
+/// 后台清理任务句柄
+pub enum CleanupHandle {
+    /// 运行在独立线程上的清理任务
+    Thread {
+        stop: mpsc::Sender<()>,
+        handle: std::thread::JoinHandle<()>,
+    },
+    /// 运行在 tokio 运行时上的清理任务
+    #[cfg(feature = "tokio")]
+    Task(tokio::task::JoinHandle<()>),
+}
+
+impl CleanupHandle {
+    /// 停止清理任务
+    ///
+    /// 线程模式下会在当前检查间隔的等待中被唤醒并退出。
+    pub fn abort(&self) {
+        match self {
+            CleanupHandle::Thread { stop, .. } => {
+                let _ = stop.send(());
+            }
+            #[cfg(feature = "tokio")]
+            CleanupHandle::Task(handle) => handle.abort(),
+        }
+    }
+
+    /// 清理任务是否已结束
+    pub fn is_finished(&self) -> bool {
+        match self {
+            CleanupHandle::Thread { handle, .. } => handle.is_finished(),
+            #[cfg(feature = "tokio")]
+            CleanupHandle::Task(handle) => handle.is_finished(),
+        }
+    }
 }
 
 impl Default for LoggerConfig {
@@ -48,6 +316,15 @@ impl Default for LoggerConfig {
             file: true,
             max_files: None,
             cleanup_interval: DEFAULT_CLEANUP_INTERVAL,
+            span_fields: Vec::new(),
+            in_memory_buffer: None,
+            locale: LogLocale::default(),
+            buffered_lines_limit: None,
+            retention_rules: Vec::new(),
+            #[cfg(feature = "tokio")]
+            async_cleanup: false,
+            #[cfg(all(unix, feature = "signal"))]
+            level_file: None,
         }
     }
 }
@@ -100,52 +377,256 @@ impl LoggerConfig {
 
     /// 设置保留的最大日志文件数量
     ///
-    /// 超过此数量的旧日志文件将被自动删除。
+    /// 超过此数量的旧日志文件将被自动删除。当前正在写入的日志文件始终保留，
+    /// 因此 `0`（或负数）表示只保留当前活动文件。
     pub fn max_files(mut self, count: i16) -> Self {
         self.max_files = Some(count);
         self
     }
 
+    /// 为同一日志目录下的其他日志流添加保留规则
+    ///
+    /// 适用于应用同时写入 `access.log`、`error.log` 等多个滚动文件的场景，每条规则按前缀独立清理。
+    /// 这些规则不依赖 `enable_file`，由同一个清理任务按 `cleanup_interval` 执行。
+    pub fn retention(mut self, prefix: impl Into<String>, max_files: i16) -> Self {
+        self.retention_rules
+            .push(RetentionRule::new(prefix, max_files));
+        self
+    }
+
+    /// 添加完整的保留规则，可同时限制文件数量与保留时间（见 `RetentionRule::max_age`）
+    pub fn retention_rule(mut self, rule: RetentionRule) -> Self {
+        self.retention_rules.push(rule);
+        self
+    }
+
     /// 设置日志清理任务的检查间隔
     pub fn cleanup_interval(mut self, interval: Duration) -> Self {
         self.cleanup_interval = interval;
         self
     }
 
+    /// 设置作为日志行前缀输出的 span 字段
+    ///
+    /// 当前事件所在的 span（含父级 span）上记录了这些字段时，会以 `[request_id=.. trace_id=..]`
+    /// 的形式输出在每行日志开头，多层 span 中以最内层的值为准。
+    pub fn span_fields<I, F>(mut self, fields: I) -> Self
+    where
+        I: IntoIterator<Item = F>,
+        F: Into<String>,
+    {
+        self.span_fields = fields.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 在内存中保留最近 `capacity` 条格式化日志，可通过 `recent_logs` 读取
+    ///
+    /// 默认关闭。该缓冲区不受控制台/文件输出开关影响，适合管理端展示近期日志。
+    pub fn in_memory_buffer(mut self, capacity: usize) -> Self {
+        self.in_memory_buffer = Some(capacity);
+        self
+    }
+
+    /// 设置日志组件自身提示信息（配置警告、清理失败等）的语言，默认英文
+    pub fn locale(mut self, locale: LogLocale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// 设置异步文件写入器的缓冲行数上限
+    ///
+    /// 缓冲区写满时新日志会被丢弃而不是阻塞调用方，丢弃数量可通过 `logger_stats` 查看。
+    /// 未设置时使用 `tracing_appender` 的默认值（128,000 行）。
+    pub fn buffered_lines_limit(mut self, limit: usize) -> Self {
+        self.buffered_lines_limit = Some(limit);
+        self
+    }
+
+    /// 在 tokio 运行时上执行清理任务
+    ///
+    /// 启用后，若 `init` 调用时存在 tokio 运行时，清理任务会以 `tokio::task` 运行，
+    /// 随运行时一同关闭；否则回退到独立线程。
+    #[cfg(feature = "tokio")]
+    pub fn async_cleanup(mut self, enable: bool) -> Self {
+        self.async_cleanup = enable;
+        self
+    }
+
+    /// 收到 SIGHUP 时从 `path` 重新读取日志级别（见 `reload_level_from_file`）
+    ///
+    /// 监听线程在进程生命周期内持续运行。
+    #[cfg(all(unix, feature = "signal"))]
+    pub fn reload_level_on_sighup(mut self, path: impl Into<PathBuf>) -> Self {
+        self.level_file = Some(path.into());
+        self
+    }
+
     /// 初始化日志系统
     ///
     /// 该方法会消耗配置对象，注册全局 tracing subscriber，并启动清理任务（如果配置了 max_files）。
-    /// 返回的 `Option<WorkerGuard>` 必须被持有，以确保异步日志在程序结束前被刷新。
-    pub fn init(self) -> Option<WorkerGuard> {
+    /// 返回的 `LoggerGuard` 必须被持有，以确保异步日志在程序结束前被刷新、清理任务持续运行。
+    ///
+    /// 日志目录无法创建、不可写或已存在全局 subscriber 时会 panic，需要自行处理错误时请使用 `try_init`。
+    pub fn init(self) -> LoggerGuard {
+        self.try_init()
+            .unwrap_or_else(|e| panic!("Failed to initialize logger: {}", e))
+    }
+
+    /// 初始化日志系统，日志目录不可用或已存在全局 subscriber 时返回错误
+    ///
+    /// 启用文件输出时，会在注册 subscriber 之前创建日志目录并进行写入测试，
+    /// 以便在启动阶段尽早发现挂载错误、权限不足等问题。
+    pub fn try_init(self) -> std::io::Result<LoggerGuard> {
+        if self.file {
+            self.prepare_log_dir()?;
+        }
+
         let level_filter = self
             .level
-            .parse::<tracing_subscriber::filter::LevelFilter>()
-            .unwrap_or(tracing_subscriber::filter::LevelFilter::INFO);
+            .parse::<LevelFilter>()
+            .unwrap_or(LevelFilter::INFO);
         let time_format = self.time_format.clone();
+        let span_fields: Arc<[String]> = Arc::from(self.span_fields.clone());
+
+        // 1. 构建可重载的全局级别过滤层
+        let (filter_layer, level_handle) = reload::Layer::new(level_filter);
+
+        // 2. 构建 span 字段记录层
+        let span_fields_layer =
+            (!span_fields.is_empty()).then(|| SpanFieldsLayer::new(span_fields.clone()));
 
-        // 1. 构建控制台层
-        let console_layer = self.build_console_layer(&time_format, level_filter);
+        // 3. 构建控制台层
+        let console_layer = self.build_console_layer(&time_format, span_fields.clone());
 
-        // 2. 构建文件层
-        let (file_layer, guard) = self.build_file_layer(&time_format, level_filter);
+        // 4. 构建文件层
+        let (file_layer, guard) = self.build_file_layer(&time_format, span_fields.clone());
 
-        // 3. 注册 Subscriber
+        // 5. 构建内存缓冲层
+        let recent_layer = self.build_recent_layer(&time_format, span_fields);
+
+        #[cfg(all(unix, feature = "signal"))]
+        let signals = self.register_sighup()?;
+
+        // 6. 注册 Subscriber
         Registry::default()
+            .with(filter_layer)
+            .with(span_fields_layer)
             .with(console_layer)
             .with(file_layer)
-            .init();
+            .with(recent_layer)
+            .try_init()
+            .map_err(|e| {
+                std::io::Error::new(
+                    std::io::ErrorKind::AlreadyExists,
+                    format!("failed to set global subscriber: {}", e),
+                )
+            })?;
+
+        let _ = LEVEL_HANDLE.set(level_handle);
+        let _ = CURRENT_CONFIG.set(RwLock::new(self.snapshot(level_filter)));
+
+        // 7. 检查配置组合并提示
+        self.warn_misconfiguration();
+
+        // 8. 启动清理任务
+        let cleanup = self.spawn_cleanup_task_if_needed();
+
+        *WORKER_GUARD.lock().unwrap_or_else(|e| e.into_inner()) = guard;
+
+        // 9. 启动 SIGHUP 监听
+        #[cfg(all(unix, feature = "signal"))]
+        self.spawn_sighup_listener(signals);
+
+        Ok(LoggerGuard { cleanup })
+    }
+
+    /// 注册 SIGHUP 信号（需在注册 subscriber 之前调用，失败时不留下任何全局状态）
+    #[cfg(all(unix, feature = "signal"))]
+    fn register_sighup(&self) -> std::io::Result<Option<signal_hook::iterator::Signals>> {
+        if self.level_file.is_none() {
+            return Ok(None);
+        }
+        signal_hook::iterator::Signals::new([signal_hook::consts::SIGHUP]).map(Some)
+    }
+
+    /// 启动 SIGHUP 监听线程，收到信号后重新读取级别配置文件
+    ///
+    /// 此时 subscriber 已注册，线程创建失败仅记录警告，不影响日志系统本身。
+    #[cfg(all(unix, feature = "signal"))]
+    fn spawn_sighup_listener(&self, signals: Option<signal_hook::iterator::Signals>) {
+        let (Some(mut signals), Some(path)) = (signals, self.level_file.clone()) else {
+            return;
+        };
+        let spawned = std::thread::Builder::new()
+            .name("rivus-logger-sighup".to_string())
+            .spawn(move || {
+                for _ in signals.forever() {
+                    // 失败已在内部记录，保留原有级别
+                    let _ = reload_level_from_file(&path);
+                }
+            });
+        if let Err(e) = spawned {
+            tracing::warn!(
+                "{}",
+                Message::SighupListenerFailed.render(self.locale, &[&e])
+            );
+        }
+    }
 
-        // 4. 启动清理任务
-        self.spawn_cleanup_task_if_needed();
+    /// 创建日志目录并验证其可写
+    fn prepare_log_dir(&self) -> std::io::Result<()> {
+        let with_context = |e: std::io::Error| {
+            std::io::Error::new(
+                e.kind(),
+                format!(
+                    "log directory {} is not writable: {}",
+                    self.log_dir.display(),
+                    e
+                ),
+            )
+        };
 
-        guard
+        std::fs::create_dir_all(&self.log_dir).map_err(with_context)?;
+        let probe = self
+            .log_dir
+            .join(format!(".{}.write-test", self.file_prefix));
+        std::fs::write(&probe, b"").map_err(with_context)?;
+        std::fs::remove_file(&probe).map_err(with_context)
+    }
+
+    /// 生成当前配置的只读快照
+    fn snapshot(&self, level: LevelFilter) -> LoggerSnapshot {
+        LoggerSnapshot {
+            file_prefix: self.file_prefix.clone(),
+            log_dir: self.log_dir.clone(),
+            time_format: self.time_format.clone(),
+            level: level.to_string(),
+            console: self.console,
+            file: self.file,
+            max_files: self.max_files,
+            cleanup_interval: self.cleanup_interval,
+            span_fields: self.span_fields.clone(),
+            in_memory_buffer: self.in_memory_buffer,
+            locale: self.locale,
+            buffered_lines_limit: self.buffered_lines_limit,
+            retention_rules: self.retention_rules.clone(),
+        }
+    }
+
+    /// 构建带 span 字段前缀的事件格式
+    fn event_format(
+        time_format: &str,
+        span_fields: Arc<[String]>,
+    ) -> SpanFieldsFormat<fmt::format::Format<fmt::format::Full, ChronoLocal>> {
+        let format = fmt::format().with_timer(ChronoLocal::new(time_format.to_string()));
+        SpanFieldsFormat::new(format, span_fields)
     }
 
     /// 构建控制台输出层
     fn build_console_layer<S>(
         &self,
         time_format: &str,
-        filter: tracing_subscriber::filter::LevelFilter,
+        span_fields: Arc<[String]>,
     ) -> Option<impl Layer<S>>
     where
         S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
@@ -153,9 +634,8 @@ impl LoggerConfig {
         if self.console {
             Some(
                 fmt::layer()
-                    .with_timer(ChronoLocal::new(time_format.to_string()))
-                    .with_writer(std::io::stdout)
-                    .with_filter(filter),
+                    .event_format(Self::event_format(time_format, span_fields))
+                    .with_writer(std::io::stdout),
             )
         } else {
             None
@@ -166,20 +646,23 @@ impl LoggerConfig {
     fn build_file_layer<S>(
         &self,
         time_format: &str,
-        filter: tracing_subscriber::filter::LevelFilter,
+        span_fields: Arc<[String]>,
     ) -> (Option<impl Layer<S>>, Option<WorkerGuard>)
     where
         S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
     {
         if self.file {
             let file_appender = tracing_appender::rolling::daily(&self.log_dir, &self.file_prefix);
-            let (non_blocking, guard) = tracing_appender::non_blocking(file_appender);
+            let mut builder = NonBlockingBuilder::default();
+            if let Some(limit) = self.buffered_lines_limit {
+                builder = builder.buffered_lines_limit(limit);
+            }
+            let (non_blocking, guard) = builder.finish(file_appender);
 
             let layer = fmt::layer()
-                .with_timer(ChronoLocal::new(time_format.to_string()))
+                .event_format(Self::event_format(time_format, span_fields))
                 .with_ansi(false)
-                .with_writer(non_blocking)
-                .with_filter(filter);
+                .with_writer(CountingWriter::new(non_blocking));
 
             (Some(layer), Some(guard))
         } else {
@@ -187,30 +670,148 @@ impl LoggerConfig {
         }
     }
 
-    /// 如果配置了清理策略，则启动后台清理任务
-    fn spawn_cleanup_task_if_needed(&self) {
+    /// 构建内存最近日志缓冲层
+    fn build_recent_layer<S>(
+        &self,
+        time_format: &str,
+        span_fields: Arc<[String]>,
+    ) -> Option<impl Layer<S>>
+    where
+        S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    {
+        let capacity = self.in_memory_buffer?;
+        let logs = RECENT_LOGS
+            .get_or_init(|| Arc::new(RecentLogs::new(capacity)))
+            .clone();
+
+        Some(
+            fmt::layer()
+                .event_format(Self::event_format(time_format, span_fields))
+                .with_ansi(false)
+                .with_writer(RecentLogsWriter(logs)),
+        )
+    }
+
+    /// 对容易误用的配置组合输出警告（需在 subscriber 注册后调用）
+    fn warn_misconfiguration(&self) {
         if let Some(max_files) = self.max_files {
-            if self.file {
-                let log_dir = self.log_dir.clone();
-                let file_prefix = self.file_prefix.clone();
-                let interval = self.cleanup_interval;
-                let max_files_usize = if max_files < 0 { 0 } else { max_files as usize };
-
-                std::thread::spawn(move || {
-                    loop {
-                        // 执行清理
-                        cleanup_old_logs(&log_dir, &file_prefix, max_files_usize);
-                        // 等待下一次检查
-                        std::thread::sleep(interval);
-                    }
-                });
+            if !self.file {
+                tracing::warn!(
+                    "{}",
+                    Message::MaxFilesWithoutFile.render(self.locale, &[&max_files])
+                );
+            }
+            if max_files < 0 {
+                tracing::warn!(
+                    "{}",
+                    Message::NegativeMaxFiles.render(self.locale, &[&max_files])
+                );
             }
         }
     }
+
+    /// 如果配置了清理策略，则启动后台清理任务
+    fn spawn_cleanup_task_if_needed(&self) -> Option<CleanupHandle> {
+        let primary = self
+            .max_files
+            .filter(|_| self.file)
+            .map(|max_files| RetentionRule::new(self.file_prefix.clone(), max_files));
+        let rules: Vec<RetentionRule> = primary
+            .into_iter()
+            .chain(self.retention_rules.iter().cloned())
+            .collect();
+        if rules.is_empty() {
+            return None;
+        }
+
+        let log_dir = self.log_dir.clone();
+        let interval = self.cleanup_interval;
+        let locale = self.locale;
+
+        #[cfg(feature = "tokio")]
+        if self.async_cleanup
+            && let Ok(runtime) = tokio::runtime::Handle::try_current()
+        {
+            let log_dir: Arc<Path> = Arc::from(log_dir);
+            let rules: Arc<[RetentionRule]> = Arc::from(rules);
+            let handle = runtime.spawn(async move {
+                let mut ticker = tokio::time::interval(interval);
+                loop {
+                    ticker.tick().await;
+                    // 目录遍历与删除是阻塞调用，放到阻塞线程池执行
+                    let (log_dir, rules) = (log_dir.clone(), rules.clone());
+                    let _ = tokio::task::spawn_blocking(move || {
+                        apply_retention_rules(&log_dir, &rules, locale)
+                    })
+                    .await;
+                }
+            });
+            return Some(CleanupHandle::Task(handle));
+        }
+
+        let (stop, stopped) = mpsc::channel::<()>();
+        let handle = std::thread::spawn(move || {
+            loop {
+                // 执行清理
+                apply_retention_rules(&log_dir, &rules, locale);
+                // 等待下一次检查，收到停止信号或句柄被释放时退出
+                if !matches!(
+                    stopped.recv_timeout(interval),
+                    Err(mpsc::RecvTimeoutError::Timeout)
+                ) {
+                    break;
+                }
+            }
+        });
+        Some(CleanupHandle::Thread { stop, handle })
+    }
 }
 
-/// 执行清理逻辑：保留最新的 `max_files` 个日志文件
-fn cleanup_old_logs(log_dir: &Path, file_prefix: &str, max_files: usize) {
+/// 依次按每条保留规则清理日志目录
+fn apply_retention_rules(log_dir: &Path, rules: &[RetentionRule], locale: LogLocale) {
+    for rule in rules {
+        // 更具体的规则所管理的文件不计入本规则
+        let excluded: Vec<&str> = rules
+            .iter()
+            .map(|other| other.prefix.as_str())
+            .filter(|other| *other != rule.prefix && matches_prefix(other, &rule.prefix))
+            .collect();
+        let max_files = if rule.max_files < 0 {
+            0
+        } else {
+            rule.max_files as usize
+        };
+        cleanup_old_logs(
+            log_dir,
+            &rule.prefix,
+            &excluded,
+            max_files,
+            rule.max_age,
+            locale,
+        );
+    }
+}
+
+/// 文件名是否属于以 `prefix` 命名的日志流（等于 `prefix` 或以 `prefix.` 开头）
+fn matches_prefix(file_name: &str, prefix: &str) -> bool {
+    file_name
+        .strip_prefix(prefix)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('.'))
+}
+
+/// 执行清理逻辑：保留最新的 `max_files` 个日志文件，并删除修改时间超过 `max_age` 的文件
+///
+/// 最新的文件即当前活动文件，无论 `max_files`、`max_age` 为何值都至少保留它。
+fn cleanup_old_logs(
+    log_dir: &Path,
+    file_prefix: &str,
+    excluded: &[&str],
+    max_files: usize,
+    max_age: Option<Duration>,
+    locale: LogLocale,
+) {
+    let max_files = max_files.max(1);
+
     if !log_dir.exists() {
         return;
     }
@@ -218,7 +819,7 @@ fn cleanup_old_logs(log_dir: &Path, file_prefix: &str, max_files: usize) {
     let read_dir = match std::fs::read_dir(log_dir) {
         Ok(dir) => dir,
         Err(e) => {
-            error!("Failed to read log directory: {}", e);
+            error!("{}", Message::ReadLogDirFailed.render(locale, &[&e]));
             return;
         }
     };
@@ -229,7 +830,11 @@ fn cleanup_old_logs(log_dir: &Path, file_prefix: &str, max_files: usize) {
             let entry = entry.ok()?;
             let file_name = entry.file_name().into_string().ok()?;
 
-            if file_name.starts_with(file_prefix) {
+            let matches = matches_prefix(&file_name, file_prefix)
+                && !excluded
+                    .iter()
+                    .any(|prefix| matches_prefix(&file_name, prefix));
+            if matches {
                 Some((entry, file_name))
             } else {
                 None
@@ -241,12 +846,108 @@ fn cleanup_old_logs(log_dir: &Path, file_prefix: &str, max_files: usize) {
     // 排序后：[app.log.2023-10-02, app.log.2023-10-01, ...]
     log_files.sort_by(|a, b| b.1.cmp(&a.1));
 
-    // 删除多余的旧文件
-    if log_files.len() > max_files {
-        for (entry, _) in log_files.iter().skip(max_files) {
-            if let Err(e) = std::fs::remove_file(entry.path()) {
-                error!("Failed to remove old log file {:?}: {}", entry.path(), e);
+    // 删除超出数量或过期的旧文件
+    for (index, (entry, _)) in log_files.iter().enumerate().skip(1) {
+        let expired = max_age.is_some_and(|max_age| {
+            entry
+                .metadata()
+                .and_then(|meta| meta.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .is_some_and(|age| age > max_age)
+        });
+        if (index >= max_files || expired)
+            && let Err(e) = std::fs::remove_file(entry.path())
+        {
+            let path = entry.path();
+            error!(
+                "{}",
+                Message::RemoveLogFileFailed.render(locale, &[&path.display(), &e])
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+
+    /// 收集事件 message 字段的测试层
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<String>>>);
+
+    struct MessageVisitor<'a>(&'a mut String);
+
+    impl Visit for MessageVisitor<'_> {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                self.0.push_str(&format!("{:?}", value));
             }
         }
     }
+
+    impl<S: tracing::Subscriber> Layer<S> for Capture {
+        fn on_event(
+            &self,
+            event: &tracing::Event<'_>,
+            _ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut message = String::new();
+            event.record(&mut MessageVisitor(&mut message));
+            self.0.lock().unwrap().push(message);
+        }
+    }
+
+    fn captured_warnings(config: &LoggerConfig) -> Vec<String> {
+        let capture = Capture::default();
+        let subscriber = Registry::default().with(capture.clone());
+        tracing::subscriber::with_default(subscriber, || config.warn_misconfiguration());
+        capture.0.lock().unwrap().clone()
+    }
+
+    #[test]
+    fn test_warn_max_files_without_file_output() {
+        let config = LoggerConfig::new().max_files(3).enable_file(false);
+        let warnings = captured_warnings(&config);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("file logging is disabled"));
+    }
+
+    #[test]
+    fn test_warn_negative_max_files() {
+        let config = LoggerConfig::new().max_files(-1);
+        let warnings = captured_warnings(&config);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("negative"));
+    }
+
+    #[test]
+    fn test_localized_warning() {
+        let config = LoggerConfig::new()
+            .max_files(3)
+            .enable_file(false)
+            .locale(LogLocale::Zh);
+        let warnings = captured_warnings(&config);
+        assert_eq!(
+            warnings,
+            vec!["已设置 max_files(3) 但未启用文件日志，日志清理不会执行".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_no_warning_for_valid_config() {
+        let config = LoggerConfig::new().max_files(3);
+        assert!(captured_warnings(&config).is_empty());
+    }
+
+    #[test]
+    fn test_parse_level_file() {
+        assert_eq!(parse_level_file("debug\n"), "debug");
+        assert_eq!(parse_level_file("# verbosity\n\n  warn  \n"), "warn");
+        assert_eq!(parse_level_file("level: trace"), "trace");
+        assert_eq!(parse_level_file("level = \"error\""), "error");
+        assert_eq!(parse_level_file(""), "");
+    }
 }
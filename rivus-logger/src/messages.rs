@@ -0,0 +1,118 @@
+//! 日志组件自身输出的运维提示文案（内置多语言表）
+
+use std::fmt::Display;
+
+/// 日志组件自身提示信息使用的语言
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogLocale {
+    /// 英文（默认）
+    #[default]
+    En,
+    /// 简体中文
+    Zh,
+}
+
+/// 日志组件自身输出的提示信息
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Message {
+    ReadLogDirFailed,
+    RemoveLogFileFailed,
+    MaxFilesWithoutFile,
+    NegativeMaxFiles,
+    ReloadLevelFailed,
+    #[cfg(all(unix, feature = "signal"))]
+    SighupListenerFailed,
+}
+
+impl Message {
+    fn template(self, locale: LogLocale) -> &'static str {
+        match (self, locale) {
+            (Message::ReadLogDirFailed, LogLocale::En) => "Failed to read log directory: {0}",
+            (Message::ReadLogDirFailed, LogLocale::Zh) => "读取日志目录失败：{0}",
+            (Message::RemoveLogFileFailed, LogLocale::En) => {
+                "Failed to remove old log file {0}: {1}"
+            }
+            (Message::RemoveLogFileFailed, LogLocale::Zh) => "删除旧日志文件 {0} 失败：{1}",
+            (Message::MaxFilesWithoutFile, LogLocale::En) => {
+                "max_files({0}) is set but file logging is disabled; log cleanup will not run"
+            }
+            (Message::MaxFilesWithoutFile, LogLocale::Zh) => {
+                "已设置 max_files({0}) 但未启用文件日志，日志清理不会执行"
+            }
+            (Message::NegativeMaxFiles, LogLocale::En) => {
+                "max_files({0}) is negative and is treated as 0; only the active log file will be kept"
+            }
+            (Message::NegativeMaxFiles, LogLocale::Zh) => {
+                "max_files({0}) 为负数，将按 0 处理，仅保留当前日志文件"
+            }
+            (Message::ReloadLevelFailed, LogLocale::En) => {
+                "Failed to reload log level from {0}: {1}; keeping the current level"
+            }
+            (Message::ReloadLevelFailed, LogLocale::Zh) => {
+                "从 {0} 重新加载日志级别失败：{1}，保留当前级别"
+            }
+            #[cfg(all(unix, feature = "signal"))]
+            (Message::SighupListenerFailed, LogLocale::En) => {
+                "Failed to start the SIGHUP listener: {0}; log level reload on SIGHUP is disabled"
+            }
+            #[cfg(all(unix, feature = "signal"))]
+            (Message::SighupListenerFailed, LogLocale::Zh) => {
+                "启动 SIGHUP 监听失败：{0}，收到 SIGHUP 时将不会重新加载日志级别"
+            }
+        }
+    }
+
+    /// 按语言渲染文案，`{n}` 替换为第 n 个参数
+    ///
+    /// 单次扫描模板，参数内容中的 `{n}` 不会被再次替换。
+    pub(crate) fn render(self, locale: LogLocale, args: &[&dyn Display]) -> String {
+        let mut text = String::new();
+        let mut rest = self.template(locale);
+        while let Some(start) = rest.find('{') {
+            text.push_str(&rest[..start]);
+            let placeholder = rest[start + 1..].split_once('}').and_then(|(index, _)| {
+                let arg = args.get(index.parse::<usize>().ok()?)?;
+                Some((arg, index.len() + 2))
+            });
+            match placeholder {
+                Some((arg, len)) => {
+                    text.push_str(&arg.to_string());
+                    rest = &rest[start + len..];
+                }
+                None => {
+                    text.push('{');
+                    rest = &rest[start + 1..];
+                }
+            }
+        }
+        text.push_str(rest);
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_localized() {
+        let path = "/var/log/app.log.2023-10-01";
+        let err = "permission denied";
+        assert_eq!(
+            Message::RemoveLogFileFailed.render(LogLocale::En, &[&path, &err]),
+            "Failed to remove old log file /var/log/app.log.2023-10-01: permission denied"
+        );
+        assert_eq!(
+            Message::RemoveLogFileFailed.render(LogLocale::Zh, &[&path, &err]),
+            "删除旧日志文件 /var/log/app.log.2023-10-01 失败：permission denied"
+        );
+    }
+    #[test]
+    fn test_render_does_not_expand_placeholders_in_args() {
+        let path = "/logs/{1}/app.log";
+        assert_eq!(
+            Message::RemoveLogFileFailed.render(LogLocale::En, &[&path, &"denied"]),
+            "Failed to remove old log file /logs/{1}/app.log: denied"
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! 在内存中保留最近 N 条格式化日志
+
+use std::collections::VecDeque;
+use std::io;
+use std::sync::{Arc, Mutex};
+use tracing_subscriber::fmt::MakeWriter;
+
+/// 有界的最近日志缓冲区
+pub(crate) struct RecentLogs {
+    capacity: usize,
+    lines: Mutex<VecDeque<String>>,
+}
+
+impl RecentLogs {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            lines: Mutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    fn push(&self, line: String) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        if lines.len() == self.capacity {
+            lines.pop_front();
+        }
+        lines.push_back(line);
+    }
+
+    /// 按时间顺序（旧 → 新）返回缓冲的日志
+    pub(crate) fn lines(&self) -> Vec<String> {
+        let lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        lines.iter().cloned().collect()
+    }
+}
+
+/// 为 fmt 层提供写入器，每个事件写完（drop）时作为一行存入缓冲区
+#[derive(Clone)]
+pub(crate) struct RecentLogsWriter(pub(crate) Arc<RecentLogs>);
+
+pub(crate) struct LineWriter {
+    logs: Arc<RecentLogs>,
+    buf: Vec<u8>,
+}
+
+impl io::Write for LineWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for LineWriter {
+    fn drop(&mut self) {
+        if self.buf.is_empty() {
+            return;
+        }
+        let line = String::from_utf8_lossy(&self.buf);
+        self.logs.push(line.trim_end_matches('\n').to_string());
+    }
+}
+
+impl<'a> MakeWriter<'a> for RecentLogsWriter {
+    type Writer = LineWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        LineWriter {
+            logs: self.0.clone(),
+            buf: Vec::new(),
+        }
+    }
+}
@@ -0,0 +1,215 @@
+//! 将指定的 span 字段（如 `request_id`）作为前缀输出到每一行日志
+
+use std::fmt;
+use std::sync::Arc;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::Layer;
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+
+/// 存放在 span extensions 中的字段值
+#[derive(Default)]
+struct SpanFieldValues(Vec<(&'static str, String)>);
+
+impl SpanFieldValues {
+    fn set(&mut self, name: &'static str, value: String) {
+        match self.0.iter_mut().find(|(k, _)| *k == name) {
+            Some(entry) => entry.1 = value,
+            None => self.0.push((name, value)),
+        }
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| *k == name)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+struct FieldVisitor<'a> {
+    fields: &'a [String],
+    values: &'a mut SpanFieldValues,
+}
+
+impl FieldVisitor<'_> {
+    fn wanted(&self, field: &Field) -> bool {
+        self.fields.iter().any(|f| f == field.name())
+    }
+}
+
+impl Visit for FieldVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if self.wanted(field) {
+            self.values.set(field.name(), value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if self.wanted(field) {
+            self.values.set(field.name(), format!("{:?}", value));
+        }
+    }
+}
+
+/// 记录 span 上配置字段值的层
+pub(crate) struct SpanFieldsLayer {
+    fields: Arc<[String]>,
+}
+
+impl SpanFieldsLayer {
+    pub(crate) fn new(fields: Arc<[String]>) -> Self {
+        Self { fields }
+    }
+}
+
+impl<S> Layer<S> for SpanFieldsLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else { return };
+        let mut values = SpanFieldValues::default();
+        attrs.record(&mut FieldVisitor {
+            fields: &self.fields,
+            values: &mut values,
+        });
+        if !values.0.is_empty() {
+            span.extensions_mut().insert(values);
+        }
+    }
+
+    fn on_record(&self, id: &Id, record: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else { return };
+        let mut extensions = span.extensions_mut();
+        if extensions.get_mut::<SpanFieldValues>().is_none() {
+            extensions.insert(SpanFieldValues::default());
+        }
+        if let Some(values) = extensions.get_mut::<SpanFieldValues>() {
+            record.record(&mut FieldVisitor {
+                fields: &self.fields,
+                values,
+            });
+        }
+    }
+}
+
+/// 在内部格式前输出 `[request_id=.. trace_id=..] ` 前缀的事件格式
+pub(crate) struct SpanFieldsFormat<F> {
+    inner: F,
+    fields: Arc<[String]>,
+}
+
+impl<F> SpanFieldsFormat<F> {
+    pub(crate) fn new(inner: F, fields: Arc<[String]>) -> Self {
+        Self { inner, fields }
+    }
+}
+
+impl<S, N, F> FormatEvent<S, N> for SpanFieldsFormat<F>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+    F: FormatEvent<S, N>,
+{
+    fn format_event(
+        &self,
+        ctx: &FmtContext<'_, S, N>,
+        mut writer: Writer<'_>,
+        event: &Event<'_>,
+    ) -> fmt::Result {
+        if !self.fields.is_empty()
+            && let Some(scope) = ctx.event_scope()
+        {
+            let spans: Vec<_> = scope.collect();
+            let mut prefix = Vec::new();
+            for name in self.fields.iter() {
+                // 由内向外查找，最内层 span 的值优先
+                let value = spans.iter().find_map(|span| {
+                    let extensions = span.extensions();
+                    let values = extensions.get::<SpanFieldValues>()?;
+                    values.get(name).map(str::to_string)
+                });
+                if let Some(value) = value {
+                    prefix.push(format!("{}={}", name, value));
+                }
+            }
+            if !prefix.is_empty() {
+                write!(writer, "[{}] ", prefix.join(" "))?;
+            }
+        }
+        self.inner.format_event(ctx, writer, event)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::sync::Mutex;
+    use tracing_subscriber::fmt;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl io::Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn capture(f: impl FnOnce()) -> String {
+        let fields: Arc<[String]> = Arc::from(vec!["request_id".to_string()]);
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::registry()
+            .with(SpanFieldsLayer::new(fields.clone()))
+            .with(
+                fmt::layer()
+                    .event_format(SpanFieldsFormat::new(fmt::format().without_time(), fields))
+                    .with_ansi(false)
+                    .with_writer(move || writer.clone()),
+            );
+        tracing::subscriber::with_default(subscriber, f);
+        let output = buffer.0.lock().unwrap().clone();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_span_field_prefix() {
+        let output = capture(|| {
+            let span = tracing::info_span!("request", request_id = "abc-123", user = 7);
+            let _entered = span.enter();
+            tracing::info!("handled");
+        });
+        assert!(output.starts_with("[request_id=abc-123] "), "{}", output);
+        assert!(output.contains("handled"));
+    }
+
+    #[test]
+    fn test_span_field_recorded_later() {
+        let output = capture(|| {
+            let span = tracing::info_span!("request", request_id = tracing::field::Empty);
+            span.record("request_id", 42);
+            let _entered = span.enter();
+            tracing::info!("handled");
+        });
+        assert!(output.starts_with("[request_id=42] "), "{}", output);
+    }
+
+    #[test]
+    fn test_no_prefix_outside_span() {
+        let output = capture(|| tracing::info!("plain"));
+        assert!(!output.starts_with('['), "{}", output);
+    }
+}
@@ -0,0 +1,65 @@
+//! 文件日志写入量与丢弃量统计
+
+use std::io;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing_appender::non_blocking::{ErrorCounter, NonBlocking};
+use tracing_subscriber::fmt::MakeWriter;
+
+/// 提交给异步文件写入器的日志行数
+static LINES_SUBMITTED: AtomicU64 = AtomicU64::new(0);
+/// 异步文件写入器的丢弃计数，在 `init` 时设置
+static DROPPED_COUNTER: OnceLock<ErrorCounter> = OnceLock::new();
+
+/// 文件日志写入统计
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoggerStats {
+    /// 成功进入写入队列的日志行数
+    pub lines_written: u64,
+    /// 因缓冲区已满被丢弃的日志行数
+    pub lines_dropped: u64,
+}
+
+/// 获取文件日志的写入统计，未启用文件输出时均为 0
+pub fn logger_stats() -> LoggerStats {
+    let submitted = LINES_SUBMITTED.load(Ordering::Relaxed);
+    let dropped = DROPPED_COUNTER
+        .get()
+        .map(|counter| counter.dropped_lines() as u64)
+        .unwrap_or(0);
+    LoggerStats {
+        lines_written: submitted.saturating_sub(dropped),
+        lines_dropped: dropped,
+    }
+}
+
+/// 统计写入行数的 `NonBlocking` 包装
+#[derive(Clone)]
+pub(crate) struct CountingWriter(NonBlocking);
+
+impl CountingWriter {
+    pub(crate) fn new(writer: NonBlocking) -> Self {
+        let _ = DROPPED_COUNTER.set(writer.error_counter());
+        Self(writer)
+    }
+}
+
+impl io::Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.0.write(buf)?;
+        LINES_SUBMITTED.fetch_add(1, Ordering::Relaxed);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
+}
+
+impl<'a> MakeWriter<'a> for CountingWriter {
+    type Writer = CountingWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
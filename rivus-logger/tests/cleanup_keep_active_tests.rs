@@ -0,0 +1,53 @@
+use rivus_logger::LoggerConfig;
+use std::fs::File;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn test_max_files_zero_keeps_active_file() {
+    let dir = "./target/cleanup_keep_active_logs";
+    if Path::new(dir).exists() {
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+    std::fs::create_dir_all(dir).unwrap();
+
+    let file_name = "keep_active.log";
+    let rotated: Vec<_> = ["2023-10-20", "2023-10-21", "2023-10-22"]
+        .iter()
+        .map(|date| format!("{}.{}", file_name, date))
+        .collect();
+    for name in &rotated {
+        File::create(format!("{}/{}", dir, name)).unwrap();
+    }
+
+    let _guard = LoggerConfig::new()
+        .log_dir(dir)
+        .file_prefix(file_name)
+        .max_files(0)
+        .enable_console(false)
+        .init();
+
+    tracing::info!("still logging");
+    thread::sleep(Duration::from_millis(500));
+
+    let files: Vec<_> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+
+    for name in &rotated {
+        assert!(
+            !files.contains(name),
+            "rotated file {} should be removed",
+            name
+        );
+    }
+    assert_eq!(
+        files.len(),
+        1,
+        "only the active file should remain: {:?}",
+        files
+    );
+    assert!(files[0].starts_with(file_name));
+}
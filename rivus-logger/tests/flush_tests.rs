@@ -0,0 +1,31 @@
+use rivus_logger::{LoggerConfig, flush_logs};
+use std::path::Path;
+
+#[test]
+fn test_flush_logs_writes_buffered_lines() {
+    let dir = "./target/flush_logs";
+    if Path::new(dir).exists() {
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    let _guard = LoggerConfig::new()
+        .log_dir(dir)
+        .file_prefix("flush.log")
+        .enable_console(false)
+        .init();
+
+    for i in 0..100 {
+        tracing::info!("buffered line {}", i);
+    }
+    flush_logs();
+
+    let content: String = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|e| std::fs::read_to_string(e.unwrap().path()).unwrap())
+        .collect();
+    assert!(content.contains("buffered line 0"));
+    assert!(content.contains("buffered line 99"));
+
+    // 重复调用无副作用
+    flush_logs();
+}
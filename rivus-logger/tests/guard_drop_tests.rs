@@ -0,0 +1,31 @@
+use rivus_logger::LoggerConfig;
+use std::fs::File;
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn test_dropping_guard_stops_cleanup() {
+    let dir = "./target/guard_drop_logs";
+    if Path::new(dir).exists() {
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+    std::fs::create_dir_all(dir).unwrap();
+
+    let file_name = "guard_drop.log";
+    let guard = LoggerConfig::new()
+        .log_dir(dir)
+        .file_prefix(file_name)
+        .max_files(1)
+        .cleanup_interval(Duration::from_millis(100))
+        .enable_console(false)
+        .init();
+    thread::sleep(Duration::from_millis(200));
+    drop(guard);
+
+    // 守卫释放后清理任务已停止，新出现的旧文件不再被删除
+    let old_file = format!("{}/{}.{}", dir, file_name, "2023-10-20");
+    File::create(&old_file).unwrap();
+    thread::sleep(Duration::from_millis(400));
+    assert!(Path::new(&old_file).exists());
+}
@@ -0,0 +1,39 @@
+use rivus_logger::{LevelError, LoggerConfig, current_log_config, reload_level_from_file};
+use std::path::Path;
+use tracing::level_filters::LevelFilter;
+
+#[test]
+fn test_reload_level_from_file() {
+    let dir = "./target/level_file_reload";
+    if Path::new(dir).exists() {
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+    std::fs::create_dir_all(dir).unwrap();
+    let level_file = format!("{}/level", dir);
+
+    let _guard = LoggerConfig::new()
+        .level("info")
+        .enable_console(false)
+        .enable_file(false)
+        .init();
+
+    std::fs::write(&level_file, "level: debug\n").unwrap();
+    reload_level_from_file(&level_file).unwrap();
+    assert_eq!(LevelFilter::current(), LevelFilter::DEBUG);
+    assert_eq!(current_log_config().unwrap().level, "debug");
+
+    // 非法级别保留原有级别
+    std::fs::write(&level_file, "verbose\n").unwrap();
+    assert!(matches!(
+        reload_level_from_file(&level_file),
+        Err(LevelError::Parse(_))
+    ));
+    assert_eq!(LevelFilter::current(), LevelFilter::DEBUG);
+
+    // 文件缺失同样保留原有级别
+    assert!(matches!(
+        reload_level_from_file(format!("{}/missing", dir)),
+        Err(LevelError::Io(_))
+    ));
+    assert_eq!(current_log_config().unwrap().level, "debug");
+}
@@ -0,0 +1,36 @@
+use rivus_logger::{LevelError, LoggerConfig, current_log_config, set_log_level};
+use tracing::level_filters::LevelFilter;
+
+#[test]
+fn test_snapshot_and_level_reload() {
+    assert!(current_log_config().is_none());
+    assert!(matches!(
+        set_log_level("debug"),
+        Err(LevelError::NotInitialized)
+    ));
+
+    let _guard = LoggerConfig::new()
+        .level("INFO")
+        .max_files(5)
+        .enable_console(false)
+        .enable_file(false)
+        .init();
+
+    let snapshot = current_log_config().unwrap();
+    assert_eq!(snapshot.level, "info");
+    assert_eq!(snapshot.max_files, Some(5));
+    assert!(!snapshot.file);
+    assert_eq!(LevelFilter::current(), LevelFilter::INFO);
+
+    set_log_level("debug").unwrap();
+    assert_eq!(current_log_config().unwrap().level, "debug");
+    assert_eq!(LevelFilter::current(), LevelFilter::DEBUG);
+
+    // 非法级别不影响当前配置
+    assert!(matches!(
+        set_log_level("verbose"),
+        Err(LevelError::Parse(_))
+    ));
+    assert_eq!(current_log_config().unwrap().level, "debug");
+    assert_eq!(LevelFilter::current(), LevelFilter::DEBUG);
+}
@@ -0,0 +1,40 @@
+use rivus_logger::LoggerConfig;
+use std::path::Path;
+
+#[test]
+fn test_try_init_rejects_unwritable_log_dir() {
+    let root = "./target/log_dir_checks";
+    if Path::new(root).exists() {
+        std::fs::remove_dir_all(root).unwrap();
+    }
+    std::fs::create_dir_all(root).unwrap();
+
+    // 以普通文件作为父路径，目录无法创建（对 root 用户同样有效）
+    let blocker = format!("{}/not_a_dir", root);
+    std::fs::write(&blocker, b"").unwrap();
+    let bad_dir = format!("{}/logs", blocker);
+
+    let err = LoggerConfig::new()
+        .log_dir(&bad_dir)
+        .enable_console(false)
+        .try_init()
+        .err()
+        .expect("unwritable log dir should be rejected");
+    assert!(err.to_string().contains(&bad_dir), "{}", err);
+    assert!(err.to_string().contains("not writable"), "{}", err);
+
+    // 校验失败时不会注册全局 subscriber，修正目录后仍可初始化
+    let good_dir = format!("{}/logs", root);
+    let _guard = LoggerConfig::new()
+        .log_dir(&good_dir)
+        .enable_console(false)
+        .try_init()
+        .unwrap();
+    assert!(Path::new(&good_dir).is_dir());
+    let leftovers: Vec<_> = std::fs::read_dir(&good_dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .filter(|name| name.contains("write-test"))
+        .collect();
+    assert!(leftovers.is_empty(), "{:?}", leftovers);
+}
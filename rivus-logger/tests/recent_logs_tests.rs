@@ -0,0 +1,23 @@
+use rivus_logger::{LoggerConfig, recent_logs};
+
+#[test]
+fn test_recent_logs_keeps_last_n_events() {
+    assert!(recent_logs().is_empty());
+
+    let _guard = LoggerConfig::new()
+        .in_memory_buffer(3)
+        .enable_console(false)
+        .enable_file(false)
+        .init();
+
+    for i in 1..=5 {
+        tracing::info!("event {}", i);
+    }
+
+    let logs = recent_logs();
+    assert_eq!(logs.len(), 3);
+    assert!(logs[0].ends_with("event 3"), "{:?}", logs);
+    assert!(logs[1].ends_with("event 4"), "{:?}", logs);
+    assert!(logs[2].ends_with("event 5"), "{:?}", logs);
+    assert!(logs.iter().all(|line| line.contains("INFO")));
+}
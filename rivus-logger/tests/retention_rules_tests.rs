@@ -0,0 +1,66 @@
+use rivus_logger::{LoggerConfig, RetentionRule};
+use std::fs::File;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+#[test]
+fn test_independent_retention_per_prefix() {
+    let dir = "./target/retention_rules_logs";
+    if Path::new(dir).exists() {
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+    std::fs::create_dir_all(dir).unwrap();
+
+    let dates = ["2023-10-20", "2023-10-21", "2023-10-22", "2023-10-23"];
+    for prefix in ["access.log", "error.log", "app", "app.access"] {
+        for date in &dates {
+            File::create(format!("{}/{}.{}", dir, prefix, date)).unwrap();
+        }
+    }
+    // 按修改时间过期：两天前的文件会被删除，最新文件始终保留
+    let two_days_ago = SystemTime::now() - Duration::from_secs(2 * 24 * 3600);
+    for date in &dates {
+        let file = File::create(format!("{}/audit.log.{}", dir, date)).unwrap();
+        if *date != "2023-10-23" {
+            file.set_modified(two_days_ago).unwrap();
+        }
+    }
+    let stale = File::create(format!("{}/audit.log.2023-10-24", dir)).unwrap();
+    stale.set_modified(two_days_ago).unwrap();
+
+    let _guard = LoggerConfig::new()
+        .log_dir(dir)
+        .enable_console(false)
+        .enable_file(false)
+        .retention("access.log", 1)
+        .retention("error.log", 3)
+        .retention("app", 1)
+        .retention("app.access", 2)
+        .retention_rule(RetentionRule::new("audit.log", 10).max_age(Duration::from_secs(3600)))
+        .cleanup_interval(Duration::from_millis(100))
+        .init();
+
+    thread::sleep(Duration::from_millis(500));
+
+    let mut files: Vec<_> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    files.sort();
+
+    assert_eq!(
+        files,
+        vec![
+            "access.log.2023-10-23",
+            "app.2023-10-23",
+            "app.access.2023-10-22",
+            "app.access.2023-10-23",
+            "audit.log.2023-10-23",
+            "audit.log.2023-10-24",
+            "error.log.2023-10-21",
+            "error.log.2023-10-22",
+            "error.log.2023-10-23",
+        ]
+    );
+}
@@ -0,0 +1,32 @@
+#![cfg(all(target_os = "linux", feature = "signal"))]
+
+use rivus_logger::LoggerConfig;
+
+fn thread_names() -> Vec<String> {
+    std::fs::read_dir("/proc/self/task")
+        .unwrap()
+        .filter_map(|task| std::fs::read_to_string(task.ok()?.path().join("comm")).ok())
+        .map(|name| name.trim().to_string())
+        .collect()
+}
+
+#[test]
+fn test_failed_init_does_not_start_sighup_listener() {
+    tracing::subscriber::set_global_default(tracing_subscriber::registry()).unwrap();
+
+    let result = LoggerConfig::new()
+        .enable_console(false)
+        .enable_file(false)
+        .reload_level_on_sighup("./target/sighup_init_failure/level")
+        .try_init();
+    assert!(result.is_err());
+
+    // 线程名在 Linux 上截断为 15 个字符
+    assert!(
+        !thread_names()
+            .iter()
+            .any(|name| name.starts_with("rivus-logger")),
+        "{:?}",
+        thread_names()
+    );
+}
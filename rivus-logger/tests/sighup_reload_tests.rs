@@ -0,0 +1,35 @@
+#![cfg(all(unix, feature = "signal"))]
+
+use rivus_logger::LoggerConfig;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use tracing::level_filters::LevelFilter;
+
+#[test]
+fn test_sighup_reloads_level_file() {
+    let dir = "./target/sighup_reload";
+    if Path::new(dir).exists() {
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+    std::fs::create_dir_all(dir).unwrap();
+    let level_file = format!("{}/level", dir);
+
+    let _guard = LoggerConfig::new()
+        .level("info")
+        .enable_console(false)
+        .enable_file(false)
+        .reload_level_on_sighup(&level_file)
+        .init();
+
+    std::fs::write(&level_file, "trace").unwrap();
+    signal_hook::low_level::raise(signal_hook::consts::SIGHUP).unwrap();
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while LevelFilter::current() != LevelFilter::TRACE {
+        assert!(
+            Instant::now() < deadline,
+            "level was not reloaded on SIGHUP"
+        );
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
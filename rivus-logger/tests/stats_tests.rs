@@ -0,0 +1,31 @@
+use rivus_logger::{LoggerConfig, logger_stats};
+use std::path::Path;
+
+#[test]
+fn test_stats_count_written_and_dropped_lines() {
+    let dir = "./target/stats_logs";
+    if Path::new(dir).exists() {
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    let stats = logger_stats();
+    assert_eq!(stats.lines_written, 0);
+    assert_eq!(stats.lines_dropped, 0);
+
+    let _guard = LoggerConfig::new()
+        .log_dir(dir)
+        .file_prefix("stats.log")
+        .buffered_lines_limit(1)
+        .enable_console(false)
+        .init();
+
+    let total = 10_000;
+    for i in 0..total {
+        tracing::info!("line {}", i);
+    }
+
+    let stats = logger_stats();
+    assert!(stats.lines_dropped > 0, "{:?}", stats);
+    assert!(stats.lines_written > 0, "{:?}", stats);
+    assert_eq!(stats.lines_written + stats.lines_dropped, total);
+}
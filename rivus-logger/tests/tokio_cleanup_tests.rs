@@ -0,0 +1,40 @@
+#![cfg(feature = "tokio")]
+
+use rivus_logger::{CleanupHandle, LoggerConfig};
+use std::fs::File;
+use std::path::Path;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_cleanup_runs_on_tokio_runtime() {
+    let dir = "./target/tokio_cleanup_logs";
+    if Path::new(dir).exists() {
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+    std::fs::create_dir_all(dir).unwrap();
+
+    let file_name = "tokio_cleanup.log";
+    for date in ["2023-10-20", "2023-10-21", "2023-10-22"] {
+        File::create(format!("{}/{}.{}", dir, file_name, date)).unwrap();
+    }
+
+    let guard = LoggerConfig::new()
+        .log_dir(dir)
+        .file_prefix(file_name)
+        .max_files(2)
+        .cleanup_interval(Duration::from_millis(100))
+        .async_cleanup(true)
+        .enable_console(false)
+        .init();
+
+    let handle = guard.cleanup_handle().expect("cleanup should be running");
+    assert!(matches!(handle, CleanupHandle::Task(_)));
+
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    let old_file = format!("{}/{}.{}", dir, file_name, "2023-10-20");
+    assert!(!Path::new(&old_file).exists());
+
+    handle.abort();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(handle.is_finished());
+}
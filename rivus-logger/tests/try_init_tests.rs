@@ -0,0 +1,15 @@
+use rivus_logger::{LoggerConfig, current_log_config};
+
+#[test]
+fn test_try_init_with_existing_global_subscriber() {
+    tracing::subscriber::set_global_default(tracing_subscriber::registry()).unwrap();
+
+    let err = LoggerConfig::new()
+        .enable_console(false)
+        .enable_file(false)
+        .try_init()
+        .err()
+        .expect("an existing global subscriber should be reported");
+    assert_eq!(err.kind(), std::io::ErrorKind::AlreadyExists);
+    assert!(current_log_config().is_none());
+}